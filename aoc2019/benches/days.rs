@@ -0,0 +1,53 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Benchmarks parsing a day's input and solving each of its parts, in a group
+/// named after the day's crate.
+macro_rules! bench_day {
+    ($c:expr, $day:ident) => {
+        bench_day!($c, $day, part1, part2)
+    };
+    ($c:expr, $day:ident, $($part:ident),+) => {{
+        const INPUT: &str = include_str!(concat!("../../", stringify!($day), "/input.txt"));
+
+        let mut group = $c.benchmark_group(stringify!($day));
+        group.sample_size(10);
+
+        group.bench_function("parse", |b| b.iter(|| $day::parse(INPUT)));
+
+        let parsed = $day::parse(INPUT);
+        $(group.bench_function(stringify!($part), |b| b.iter(|| $day::$part(&parsed)));)+
+
+        group.finish();
+    }};
+}
+
+fn days_benchmark(c: &mut Criterion) {
+    bench_day!(c, day1_rocket_equation);
+    bench_day!(c, day2_program_alarm);
+    bench_day!(c, day3_crossed_wires);
+    bench_day!(c, day4_secure_container);
+    bench_day!(c, day5_chance_of_asteroids);
+    bench_day!(c, day6_orbit_map);
+    bench_day!(c, day7_amplification_circuit);
+    bench_day!(c, day8_sif);
+    bench_day!(c, day9_sensor_boost);
+    bench_day!(c, day10_monitoring_station);
+    bench_day!(c, day11_space_police);
+    bench_day!(c, day12_n_body_problem);
+    bench_day!(c, day13_care_package);
+    bench_day!(c, day14_space_stoichiometry);
+    bench_day!(c, day15_oxygen_system);
+    bench_day!(c, day16_fft);
+    bench_day!(c, day17_set_and_forget);
+    bench_day!(c, day18_many_worlds_interpretation);
+    bench_day!(c, day19_tractor_beam);
+    bench_day!(c, day20_donut_maze);
+    bench_day!(c, day21_springdroid_adventure);
+    bench_day!(c, day22_slam_shuffle);
+    bench_day!(c, day23_category_six);
+    bench_day!(c, day24_planet_of_discord);
+    bench_day!(c, day25_cryostasis, part1);
+}
+
+criterion_group!(benches, days_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::time::Duration;
+
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+/// A known good answer, and how long it took to compute when recorded.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Recorded {
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub time_ms: f64,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub struct Answers {
+    #[serde(rename = "answer", default)]
+    answers: Vec<Recorded>,
+}
+
+impl Answers {
+    /// Loads `path`; a missing file has no answers in it.
+    pub fn load(path: &str) -> Result<Answers, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => toml::from_str(&s).map_err(|why| format!("couldn't parse {}: {}", path, why)),
+            Err(ref why) if why.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(why) => Err(format!("couldn't read {}: {}", path, why)),
+        }
+    }
+
+    pub fn save(&mut self, path: &str) -> Result<(), String> {
+        self.answers
+            .sort_by_key(|recorded| (recorded.day, recorded.part));
+
+        let s = toml::to_string(self).map_err(|why| format!("couldn't encode answers: {}", why))?;
+        fs::write(path, s).map_err(|why| format!("couldn't write {}: {}", path, why))
+    }
+
+    pub fn get(&self, day: usize, part: usize) -> Option<&Recorded> {
+        self.answers
+            .iter()
+            .find(|recorded| recorded.day == day && recorded.part == part)
+    }
+
+    /// Records `answer`, replacing whatever was known for that part before.
+    pub fn record(&mut self, day: usize, part: usize, answer: String, elapsed: Duration) {
+        self.answers
+            .retain(|recorded| recorded.day != day || recorded.part != part);
+
+        self.answers.push(Recorded {
+            day,
+            part,
+            answer,
+            time_ms: elapsed.as_micros() as f64 / 1000.0,
+        });
+    }
+}
@@ -0,0 +1,94 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+const USAGE: &str = "\
+usage: bench_table [criterion directory]
+    prints a markdown table of the median times `cargo bench -p aoc2019`
+    measured, reading them from target/criterion by default";
+
+const DEFAULT_PATH: &str = "target/criterion";
+
+const COLUMNS: [&str; 3] = ["parse", "part1", "part2"];
+
+const HEADER: &str = "\
+# Benchmarks
+
+Median times of parsing each day's input and solving each part, as measured by
+`cargo bench -p aoc2019`. To regenerate this table after running the benchmarks:
+
+```
+cargo run --release -p aoc2019 --bin bench_table > BENCHMARKS.md
+```
+";
+
+/// The median of a benchmark in nanoseconds, if it has been run.
+fn median(criterion: &Path, group: &str, function: &str) -> Option<f64> {
+    let path = criterion
+        .join(group)
+        .join(function)
+        .join("new")
+        .join("estimates.json");
+    let estimates: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+
+    estimates["median"]["point_estimate"].as_f64()
+}
+
+fn format_time(nanoseconds: f64) -> String {
+    if nanoseconds < 1e3 {
+        format!("{:.1} ns", nanoseconds)
+    } else if nanoseconds < 1e6 {
+        format!("{:.1} µs", nanoseconds / 1e3)
+    } else if nanoseconds < 1e9 {
+        format!("{:.1} ms", nanoseconds / 1e6)
+    } else {
+        format!("{:.2} s", nanoseconds / 1e9)
+    }
+}
+
+/// The day a benchmark group is for: `day16_fft` is day 16. Groups that aren't
+/// named after a day crate, like the Intcode benchmarks, have none.
+fn day_number(group: &str) -> Option<usize> {
+    let (number, _name) = group.strip_prefix("day")?.split_once('_')?;
+
+    number.parse().ok()
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.len() > 1 {
+        eprintln!("{}", USAGE);
+        process::exit(1);
+    }
+
+    let criterion = Path::new(args.first().map_or(DEFAULT_PATH, String::as_str));
+
+    let entries = fs::read_dir(criterion).unwrap_or_else(|why| {
+        eprintln!("couldn't read {}: {}\n{}", criterion.display(), why, USAGE);
+        process::exit(1);
+    });
+
+    let mut groups: Vec<(usize, String)> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|group| Some((day_number(&group)?, group)))
+        .collect();
+    groups.sort();
+
+    println!("{}", HEADER);
+    println!("| Day | Parse | Part 1 | Part 2 |");
+    println!("|----:|------:|-------:|-------:|");
+
+    for (number, group) in groups {
+        let times: Vec<String> = COLUMNS
+            .iter()
+            .map(|function| {
+                median(criterion, &group, function).map_or(String::from("—"), format_time)
+            })
+            .collect();
+
+        println!("| {} | {} |", number, times.join(" | "));
+    }
+}
@@ -0,0 +1,62 @@
+/// Solves one part of a puzzle from its input.
+pub type Solver = fn(&str) -> String;
+
+pub struct Day {
+    pub number: usize,
+    /// The crate, which is also the directory its input is in.
+    pub name: &'static str,
+    /// `None` for parts that aren't solved here.
+    pub parts: [Option<Solver>; 2],
+}
+
+/// Wraps a day's `parse` and `partN` functions into a `Solver`.
+macro_rules! solver {
+    ($day:ident, $part:ident) => {
+        Some(|input| $day::$part(&$day::parse(input)).to_string())
+    };
+}
+
+macro_rules! day {
+    ($number:expr, $day:ident) => {
+        Day {
+            number: $number,
+            name: stringify!($day),
+            parts: [solver!($day, part1), solver!($day, part2)],
+        }
+    };
+    ($number:expr, $day:ident, part1) => {
+        Day {
+            number: $number,
+            name: stringify!($day),
+            parts: [solver!($day, part1), None],
+        }
+    };
+}
+
+pub const DAYS: [Day; 25] = [
+    day!(1, day1_rocket_equation),
+    day!(2, day2_program_alarm),
+    day!(3, day3_crossed_wires),
+    day!(4, day4_secure_container),
+    day!(5, day5_chance_of_asteroids),
+    day!(6, day6_orbit_map),
+    day!(7, day7_amplification_circuit),
+    day!(8, day8_sif),
+    day!(9, day9_sensor_boost),
+    day!(10, day10_monitoring_station),
+    day!(11, day11_space_police),
+    day!(12, day12_n_body_problem),
+    day!(13, day13_care_package),
+    day!(14, day14_space_stoichiometry),
+    day!(15, day15_oxygen_system),
+    day!(16, day16_fft),
+    day!(17, day17_set_and_forget),
+    day!(18, day18_many_worlds_interpretation),
+    day!(19, day19_tractor_beam),
+    day!(20, day20_donut_maze),
+    day!(21, day21_springdroid_adventure),
+    day!(22, day22_slam_shuffle),
+    day!(23, day23_category_six),
+    day!(24, day24_planet_of_discord),
+    day!(25, day25_cryostasis, part1),
+];
@@ -0,0 +1,277 @@
+mod answers;
+mod days;
+
+use answers::Answers;
+use days::{Day, DAYS};
+use input::{load_or_exit, DEFAULT_PATH};
+use std::env;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "\
+usage: aoc2019 run <day|all> [part] [--input <path>]
+       aoc2019 verify [day|all] [part] [--answers <path>] [--tolerance <factor>] [--record]
+    <day>                1 to 25, or all for the whole calendar
+    [part]               1 or 2, both by default
+    --input <path>       the puzzle input, - for standard input; <day crate>/input.txt
+                         by default
+    --answers <path>     the recorded answers; answers.toml by default
+    --tolerance <factor> how many times slower than recorded a part may get before
+                         it counts as a regression; 2 by default
+    --record             save the answers and timings computed now as the new
+                         recorded ones";
+
+const DEFAULT_TOLERANCE: f64 = 2.0;
+
+/// Slowdowns smaller than this are noise, whatever the tolerance.
+const MIN_SLOWDOWN: Duration = Duration::from_millis(5);
+
+#[derive(PartialEq)]
+enum Command {
+    Run,
+    Verify,
+}
+
+struct Options {
+    command: Command,
+    days: Vec<&'static Day>,
+    parts: Vec<usize>,
+    input: Option<String>,
+    answers: String,
+    tolerance: f64,
+    record: bool,
+}
+
+fn parse_days(day: &str) -> Result<Vec<&'static Day>, String> {
+    if day == "all" {
+        return Ok(DAYS.iter().collect());
+    }
+
+    let day = DAYS
+        .iter()
+        .find(|d| d.number.to_string() == day)
+        .ok_or(format!("there is no day {}", day))?;
+
+    Ok(vec![day])
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("verify") => Command::Verify,
+        _ => return Err(String::from("expected a command")),
+    };
+
+    let mut options = Options {
+        command,
+        days: DAYS.iter().collect(),
+        parts: vec![1, 2],
+        input: None,
+        answers: String::from(answers::DEFAULT_PATH),
+        tolerance: DEFAULT_TOLERANCE,
+        record: false,
+    };
+
+    let mut positional = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" if options.command == Command::Run => {
+                options.input = Some(args.next().ok_or("--input needs a path")?)
+            }
+            "--answers" if options.command == Command::Verify => {
+                options.answers = args.next().ok_or("--answers needs a path")?
+            }
+            "--tolerance" if options.command == Command::Verify => {
+                options.tolerance = args
+                    .next()
+                    .and_then(|factor| factor.parse().ok())
+                    .ok_or("--tolerance needs a number")?
+            }
+            "--record" if options.command == Command::Verify => options.record = true,
+            _ if !arg.starts_with("--") => positional.push(arg),
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+
+    match positional.next() {
+        Some(day) => options.days = parse_days(&day)?,
+        None if options.command == Command::Run => return Err(String::from("expected a day")),
+        None => {}
+    }
+
+    match positional.next().as_deref() {
+        Some(part @ "1") | Some(part @ "2") => options.parts = vec![part.parse().unwrap()],
+        Some(arg) => return Err(format!("unexpected argument {}", arg)),
+        None => {}
+    }
+
+    if let Some(arg) = positional.next() {
+        return Err(format!("unexpected argument {}", arg));
+    }
+
+    if options.input.is_some() && options.days.len() > 1 {
+        return Err(String::from("--input needs a single day"));
+    }
+
+    Ok(options)
+}
+
+fn default_input(day: &Day) -> String {
+    Path::new(day.name)
+        .join(DEFAULT_PATH)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Solves `part` of `day`, if it is solved here, and times it.
+fn solve(day: &Day, part: usize, input: &str) -> Option<(String, Duration)> {
+    let solve = day.parts[part - 1]?;
+
+    let start = Instant::now();
+    let answer = solve(input);
+
+    Some((answer, start.elapsed()))
+}
+
+fn print_answer(day: &Day, part: usize, answer: &str, elapsed: Duration) {
+    if answer.contains('\n') {
+        println!("day {} part {} ({:?}):", day.number, part, elapsed);
+        print!("{}", answer);
+    } else {
+        println!(
+            "day {} part {}: {} ({:?})",
+            day.number, part, answer, elapsed
+        );
+    }
+}
+
+/// Runs the requested parts of `day` and returns the time they took.
+fn run(day: &Day, parts: &[usize], path: &str) -> Duration {
+    let input = load_or_exit(path);
+    let mut total = Duration::default();
+
+    for &part in parts {
+        match solve(day, part, &input) {
+            Some((answer, elapsed)) => {
+                total += elapsed;
+                print_answer(day, part, &answer, elapsed);
+            }
+            None => println!("day {} part {}: not available", day.number, part),
+        }
+    }
+
+    total
+}
+
+#[derive(Default)]
+struct Summary {
+    correct: usize,
+    wrong: usize,
+    slower: usize,
+    unrecorded: usize,
+}
+
+/// Checks the requested parts of `day` against `answers`, recording what is
+/// computed now if asked to.
+fn verify(day: &Day, options: &Options, answers: &mut Answers, summary: &mut Summary) {
+    let input = load_or_exit(&default_input(day));
+
+    for &part in &options.parts {
+        let (answer, elapsed) = match solve(day, part, &input) {
+            Some(solved) => solved,
+            None => continue,
+        };
+
+        match answers.get(day.number, part) {
+            None => {
+                summary.unrecorded += 1;
+                println!("day {} part {}: no recorded answer", day.number, part);
+            }
+            Some(recorded) if recorded.answer != answer => {
+                summary.wrong += 1;
+                println!("day {} part {}: wrong answer", day.number, part);
+                println!(
+                    "    expected: {}",
+                    recorded.answer.replace('\n', "\n              ")
+                );
+                println!("    got:      {}", answer.replace('\n', "\n              "));
+            }
+            Some(recorded) => {
+                let before = Duration::from_secs_f64(recorded.time_ms / 1000.0);
+
+                if elapsed.as_secs_f64() > before.as_secs_f64() * options.tolerance
+                    && elapsed > before + MIN_SLOWDOWN
+                {
+                    summary.slower += 1;
+                    println!(
+                        "day {} part {}: slower, {:?} against {:?} recorded",
+                        day.number, part, elapsed, before
+                    );
+                } else {
+                    summary.correct += 1;
+                    println!("day {} part {}: ok ({:?})", day.number, part, elapsed);
+                }
+            }
+        }
+
+        if options.record {
+            answers.record(day.number, part, answer, elapsed);
+        }
+    }
+}
+
+fn exit_with(why: String) -> ! {
+    eprintln!("{}", why);
+    process::exit(1);
+}
+
+fn main() {
+    let options = parse_options(env::args().skip(1)).unwrap_or_else(|why| {
+        eprintln!("{}\n{}", why, USAGE);
+        process::exit(1);
+    });
+
+    if options.command == Command::Verify {
+        let mut answers = Answers::load(&options.answers).unwrap_or_else(|why| exit_with(why));
+        let mut summary = Summary::default();
+
+        for day in &options.days {
+            verify(day, &options, &mut answers, &mut summary);
+        }
+
+        println!(
+            "{} ok, {} wrong, {} slower, {} unrecorded",
+            summary.correct, summary.wrong, summary.slower, summary.unrecorded
+        );
+
+        if options.record {
+            answers
+                .save(&options.answers)
+                .unwrap_or_else(|why| exit_with(why));
+            println!("recorded in {}", options.answers);
+        } else if summary.wrong > 0 || summary.slower > 0 {
+            process::exit(1);
+        }
+
+        return;
+    }
+
+    let mut total = Duration::default();
+
+    for day in &options.days {
+        let path = match &options.input {
+            Some(path) => path.clone(),
+            None => default_input(day),
+        };
+
+        total += run(day, &options.parts, &path);
+    }
+
+    if options.days.len() > 1 {
+        println!("total: {:?}", total);
+    }
+}
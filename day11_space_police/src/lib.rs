@@ -0,0 +1,151 @@
+use intcode::{Computer, IntcodeIo};
+use std::collections::{HashMap, HashSet};
+use std::ops::{Add, Mul};
+
+#[derive(Eq, PartialEq, Hash, Debug, Copy, Clone)]
+pub struct Vector2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Add<Vector2> for Vector2 {
+    type Output = Vector2;
+
+    fn add(self, other: Vector2) -> Vector2 {
+        Vector2 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+pub struct Matrix2 {
+    pub x: Vector2,
+    pub y: Vector2,
+}
+
+impl Mul<Vector2> for &Matrix2 {
+    type Output = Vector2;
+
+    fn mul(self, other: Vector2) -> Vector2 {
+        Vector2 {
+            x: self.x.x * other.x + self.y.x * other.y,
+            y: self.x.y * other.x + self.y.y * other.y,
+        }
+    }
+}
+
+const LEFT: Matrix2 = Matrix2 {
+    x: Vector2 { x: 0, y: 1 },
+    y: Vector2 { x: -1, y: 0 },
+};
+
+const RIGHT: Matrix2 = Matrix2 {
+    x: Vector2 { x: 0, y: -1 },
+    y: Vector2 { x: 1, y: 0 },
+};
+
+impl Vector2 {
+    fn rotate(self, matrix: &Matrix2) -> Self {
+        matrix * self
+    }
+}
+
+struct Robot {
+    position: Vector2,
+    orientation: Vector2,
+    picture: HashMap<Vector2, isize>,
+    painted: HashSet<Vector2>,
+    color: Option<isize>,
+}
+
+impl Robot {
+    fn init(picture: HashMap<Vector2, isize>, position: Vector2) -> Robot {
+        Robot {
+            position,
+            orientation: Vector2 { x: 0, y: 1 },
+            picture,
+            painted: HashSet::new(),
+            color: None,
+        }
+    }
+
+    fn paint(&mut self, color: isize, direction: isize) {
+        self.painted.insert(self.position);
+        self.picture.insert(self.position, color);
+
+        self.orientation = self.orientation.rotate(match direction {
+            0 => &LEFT,
+            1 => &RIGHT,
+            _ => panic!("wrong direction!"),
+        });
+
+        self.position = self.position + self.orientation;
+    }
+}
+
+impl IntcodeIo for Robot {
+    fn read(&mut self) -> Option<isize> {
+        Some(*self.picture.entry(self.position).or_insert(0))
+    }
+
+    fn write(&mut self, value: isize) {
+        match self.color.take() {
+            None => self.color = Some(value),
+            Some(color) => self.paint(color, value),
+        }
+    }
+}
+
+/// Runs the robot from a single panel of `color` until it halts.
+fn paint(computer: &Computer, color: isize) -> Robot {
+    let mut computer = computer.clone();
+    let mut robot = Robot::init(
+        [(Vector2 { x: 0, y: 0 }, color)].iter().cloned().collect(),
+        Vector2 { x: 0, y: 0 },
+    );
+    computer.compute_with_io(&mut robot).unwrap();
+
+    robot
+}
+
+pub fn parse(input: &str) -> Computer {
+    Computer::init(input).unwrap_or_else(|why| panic!("couldn't load the program: {}", why))
+}
+
+/// The number of panels painted at least once, starting on black.
+pub fn part1(computer: &Computer) -> usize {
+    paint(computer, 0).painted.len()
+}
+
+/// The panels painted starting on white, `true` for white ones.
+pub fn picture(computer: &Computer) -> Vec<Vec<bool>> {
+    let robot = paint(computer, 1);
+
+    let min_x = robot.picture.keys().map(|v| v.x).min().unwrap_or(0);
+    let max_x = robot.picture.keys().map(|v| v.x).max().unwrap_or(0);
+    let min_y = robot.picture.keys().map(|v| v.y).min().unwrap_or(0);
+    let max_y = robot.picture.keys().map(|v| v.y).max().unwrap_or(0);
+
+    (min_y..=max_y)
+        .rev()
+        .map(|y| {
+            (min_x..=max_x)
+                .map(|x| robot.picture.get(&Vector2 { x, y }).cloned().unwrap_or(0) != 0)
+                .collect()
+        })
+        .collect()
+}
+
+/// The registration identifier.
+pub fn part2(computer: &Computer) -> String {
+    let picture = picture(computer);
+
+    ocr::recognize(&picture).unwrap_or_else(|why| {
+        panic!(
+            "couldn't read the identifier: {}\n{}",
+            why,
+            ocr::render(&picture)
+        )
+    })
+}
@@ -0,0 +1,214 @@
+use num::Integer;
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::ops::{Add, Sub};
+
+const TIME: usize = 1000;
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+pub struct Vector3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Debug for Vector3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "<x = {}, y = {}, z = {}>", self.x, self.y, self.z,)
+    }
+}
+
+impl Add<Vector3> for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, other: Vector3) -> Vector3 {
+        Vector3 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Sub<Vector3> for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, other: Vector3) -> Vector3 {
+        Vector3 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl Add<&Vector3> for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, other: &Vector3) -> Vector3 {
+        Vector3 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+pub struct Asteroid {
+    position: Vector3,
+    velocity: Vector3,
+}
+
+impl Debug for Asteroid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "pos = <x = {}, y = {}, z = {}>, vel = <x = {}, y = {}, z = {}>",
+            self.position.x,
+            self.position.y,
+            self.position.z,
+            self.velocity.x,
+            self.velocity.y,
+            self.velocity.z
+        )
+    }
+}
+
+impl Asteroid {
+    fn new(position: Vector3) -> Asteroid {
+        Asteroid {
+            position,
+            velocity: Vector3 { x: 0, y: 0, z: 0 },
+        }
+    }
+
+    fn energy(&self) -> isize {
+        let potential = self.position.x.abs() + self.position.y.abs() + self.position.z.abs();
+        let kinetic = self.velocity.x.abs() + self.velocity.y.abs() + self.velocity.z.abs();
+
+        potential * kinetic
+    }
+}
+
+fn pull(a: isize, b: isize) -> isize {
+    if a < b {
+        1
+    } else if a > b {
+        -1
+    } else {
+        0
+    }
+}
+
+fn step(asteroids: &mut [Asteroid]) {
+    let mut velocity_deltas = vec![Vector3 { x: 0, y: 0, z: 0 }; asteroids.len()];
+
+    for i in 0..asteroids.len() {
+        let asteroid1 = asteroids[i];
+
+        for j in i + 1..asteroids.len() {
+            let asteroid2 = asteroids[j];
+
+            let d_velocity = Vector3 {
+                x: pull(asteroid1.position.x, asteroid2.position.x),
+                y: pull(asteroid1.position.y, asteroid2.position.y),
+                z: pull(asteroid1.position.z, asteroid2.position.z),
+            };
+
+            velocity_deltas[i] = velocity_deltas[i] + d_velocity;
+            velocity_deltas[j] = velocity_deltas[j] - d_velocity;
+        }
+    }
+
+    for (asteroid, delta) in asteroids.iter_mut().zip(velocity_deltas) {
+        asteroid.velocity = asteroid.velocity + delta;
+        asteroid.position = asteroid.position + asteroid.velocity;
+    }
+}
+
+pub type Input = Vec<Asteroid>;
+
+pub fn parse(input: &str) -> Input {
+    let mut asteroids = Vec::new();
+
+    for mut s in input.lines() {
+        s = &s[1..s.len() - 1];
+
+        let coordinates: Vec<isize> = s
+            .trim()
+            .split(',')
+            .map(|x| {
+                let y = x.trim();
+                y[2..y.len()].parse::<isize>().unwrap()
+            })
+            .collect();
+
+        asteroids.push(Asteroid::new(Vector3 {
+            x: coordinates[0],
+            y: coordinates[1],
+            z: coordinates[2],
+        }));
+    }
+
+    asteroids
+}
+
+/// The total energy in the system after `TIME` steps.
+pub fn part1(asteroids: &Input) -> isize {
+    let mut asteroids = asteroids.clone();
+
+    for _ in 0..TIME {
+        step(&mut asteroids);
+    }
+
+    asteroids.iter().map(Asteroid::energy).sum()
+}
+
+/// The number of steps until the asteroids are back where they started,
+/// moving along the one axis `coordinate` picks out.
+fn period(asteroids: &[Asteroid], coordinate: fn(&Vector3) -> isize) -> isize {
+    let along = |v: &Vector3| Vector3 {
+        x: coordinate(v),
+        y: 0,
+        z: 0,
+    };
+    let initial_state: Vec<Asteroid> = asteroids
+        .iter()
+        .map(|asteroid| Asteroid {
+            position: along(&asteroid.position),
+            velocity: along(&asteroid.velocity),
+        })
+        .collect();
+
+    let mut asteroids = initial_state.clone();
+    let mut time = 0;
+
+    loop {
+        step(&mut asteroids);
+        time += 1;
+
+        if asteroids == initial_state {
+            return time;
+        }
+    }
+}
+
+/// The number of steps until the system first repeats a previous state. The
+/// axes don't affect each other, so that is when all of their periods line up.
+pub fn part2(asteroids: &Input) -> isize {
+    part2_with_diagnostics(asteroids, &mut Vec::new())
+}
+
+/// Like `part2`, noting the period of every axis in `diagnostics`.
+pub fn part2_with_diagnostics(asteroids: &Input, diagnostics: &mut Vec<String>) -> isize {
+    let time_x = period(asteroids, |v| v.x);
+    let time_y = period(asteroids, |v| v.y);
+    let time_z = period(asteroids, |v| v.z);
+
+    diagnostics.push(format!("x repeats every {} steps", time_x));
+    diagnostics.push(format!("y repeats every {} steps", time_y));
+    diagnostics.push(format!("z repeats every {} steps", time_z));
+
+    time_z.lcm(&time_x.lcm(&time_y))
+}
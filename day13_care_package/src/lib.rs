@@ -0,0 +1,96 @@
+use intcode::Computer;
+use std::collections::HashMap;
+
+/// Tile ids by position; the score is at `(-1, 0)`.
+pub type Screen = HashMap<(isize, isize), isize>;
+
+const QUARTERS: usize = 0;
+const BLOCK: isize = 2;
+const PADDLE: isize = 3;
+const BALL: isize = 4;
+
+fn find(screen: &Screen, tile: isize) -> isize {
+    screen
+        .iter()
+        .find(|&(_, &t)| t == tile)
+        .map_or(0, |(&(x, _), _)| x)
+}
+
+/// The game with the quarters in, ready to play.
+pub fn new_game(computer: &Computer) -> Computer {
+    let mut game = computer.clone();
+    game.write_memory(QUARTERS, 2);
+    game
+}
+
+/// Plays `game` on, keeping the paddle under the ball, and returns the final
+/// score once every block is broken. `frame` sees the screen before every move
+/// and stops the game where it is by returning `false`.
+///
+/// Everything the game draws is left in its output, so a snapshot of a stopped
+/// game is resumed with the whole screen.
+pub fn resume(game: &mut Computer, mut frame: impl FnMut(&Screen) -> bool) -> Option<isize> {
+    game.compute().unwrap();
+
+    let mut screen = Screen::new();
+    let mut drawn = 0;
+
+    loop {
+        let output = game.pending_output();
+
+        for tile in output.range(drawn..).cloned().collect::<Vec<_>>().chunks(3) {
+            screen.insert((tile[0], tile[1]), tile[2]);
+        }
+
+        drawn = output.len();
+
+        let go_on = frame(&screen);
+
+        if !screen.values().any(|&tile| tile == BLOCK) {
+            return Some(screen.get(&(-1, 0)).cloned().unwrap_or(0));
+        }
+
+        if !go_on {
+            return None;
+        }
+
+        game.provide_input(match find(&screen, BALL) - find(&screen, PADDLE) {
+            x if x < 0 => -1,
+            x if x > 0 => 1,
+            _ => 0,
+        });
+
+        game.compute().unwrap();
+    }
+}
+
+/// Plays a new game to the end and returns the final score. `show` sees the
+/// screen before every move.
+pub fn play(computer: &Computer, mut show: impl FnMut(&Screen)) -> isize {
+    resume(&mut new_game(computer), |screen| {
+        show(screen);
+        true
+    })
+    .unwrap()
+}
+
+pub fn parse(input: &str) -> Computer {
+    Computer::init(input).unwrap_or_else(|why| panic!("couldn't load the program: {}", why))
+}
+
+/// The number of block tiles on the screen when the game exits.
+pub fn part1(computer: &Computer) -> usize {
+    let mut computer = computer.clone();
+    computer.write_memory(QUARTERS, 1);
+    computer.run().unwrap();
+
+    computer
+        .read_outputs()
+        .chunks(3)
+        .filter(|tile| tile[2] == BLOCK)
+        .count()
+}
+
+pub fn part2(computer: &Computer) -> isize {
+    play(computer, |_| {})
+}
@@ -1,342 +1,147 @@
-use termion::{clear, cursor, color, style};
-use std::collections::HashMap;
-use std::error::Error;
-use std::fs::File;
-use std::io::{self, Read, Write};
-use std::io::prelude::*;
-use std::path::Path;
+use day13_care_package::{new_game, parse, part1, part2, play, resume, Screen};
+use input::{load_or_exit, DEFAULT_PATH};
+use intcode::Computer;
+use report::{Format, Report};
+use std::env;
+use std::process;
 use std::{thread, time};
-
-const INPUT_PATH: &str = "input.txt";
-
-fn read_input(path: &str) -> String {
-    let path = Path::new(path);
-    let display = path.display();
-
-    let mut file = match File::open(&path) {
-        Err(why) => panic!("couldn't open {}: {}", display, why.description()),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-
-    if let Err(why) = file.read_to_string(&mut s) {
-        panic!("couldn't read {}: {}", display, why.description())
-    }
-
-    String::from(s.trim())
-}
-
-struct Computer {
-    program: HashMap<isize, isize>,
-    pointer: isize,
-    relative_base: isize,
-    input: Vec<isize>,
-    output: Vec<isize>,
-    is_halted: bool,
+use termion::{clear, color, cursor, style};
+
+const USAGE: &str = "\
+arguments: [input] [--format text|json] [--frames <n>] [--save <snapshot>]
+           [--restore <snapshot>]
+    [input]               the puzzle input, input.txt by default
+    --format <name>       text, the default, draws the game and prints the
+                          answers; json prints them with their timings
+    --frames <n>          stop the game after n frames
+    --save <snapshot>     save the game where it stopped
+    --restore <snapshot>  play on from a saved game, the input isn't read";
+
+struct Options {
+    path: String,
+    format: Format,
+    frames: Option<usize>,
+    save: Option<String>,
+    restore: Option<String>,
 }
 
-impl Computer {
-    fn init(program: &str, input: Vec<isize>) -> Computer {
-        let instructions: HashMap<isize, isize> = program
-            .trim()
-            .split(',')
-            .enumerate()
-            .map(|(x, y)| (x as isize, y.parse::<isize>().unwrap()))
-            .collect();
-
-        Computer {
-            program: instructions,
-            pointer: 0,
-            relative_base: 0,
-            input,
-            output: Vec::new(),
-            is_halted: false,
-        }
-    }
-
-    fn get_parameter_value(&mut self, parameter: isize, mode: isize) -> isize {
-        match mode {
-            0 => *self.program.entry(parameter).or_insert(0),
-            1 => parameter,
-            2 => *self
-                .program
-                .entry(parameter + self.relative_base)
-                .or_insert(0),
-            x => panic!("{} is a wrong parameter mode!", x),
-        }
-    }
-
-    fn get_input_params(&mut self, modes: &mut Vec<isize>, number_of_params: usize) -> Vec<isize> {
-        let mut params = Vec::new();
-
-        for _i in 0..number_of_params {
-            let param = *self.program.entry(self.pointer).or_insert(0);
-
-            params.push(self.get_parameter_value(
-                param,
-                if !modes.is_empty() {
-                    modes.remove(0)
-                } else {
-                    0
-                },
-            ));
-
-            self.pointer += 1;
-        }
-
-        params
-    }
-
-    fn get_output_param(&mut self, mode: isize) -> isize {
-        let param = *self.program.entry(self.pointer).or_insert(0);
-        self.pointer += 1;
-
-        match mode {
-            0 => param,
-            2 => param + self.relative_base,
-            x => panic!("{} is a wrong parameter mode for output!", x),
-        }
-    }
-
-    fn add(&mut self, modes: &mut Vec<isize>) {
-        let input_params = self.get_input_params(modes, 2);
-
-        let output_mode = if modes.is_empty() { 0 } else { modes.remove(0) };
-        let output_param = self.get_output_param(output_mode);
-
-        self.program
-            .insert(output_param, input_params[0] + input_params[1]);
-    }
-
-    fn multiply(&mut self, modes: &mut Vec<isize>) {
-        let input_params = self.get_input_params(modes, 2);
-        let output_mode = if modes.is_empty() { 0 } else { modes.remove(0) };
-        let output_param = self.get_output_param(output_mode);
-
-        self.program
-            .insert(output_param, input_params[0] * input_params[1]);
-    }
-
-    fn get_input(&mut self, modes: &mut Vec<isize>, input: isize) {
-        let output_mode = if modes.is_empty() { 0 } else { modes.remove(0) };
-        let output_param = self.get_output_param(output_mode);
-
-        self.program.insert(output_param, input);
-    }
-
-    fn calculate_output(&mut self, modes: &mut Vec<isize>) {
-        let input_param = self.get_input_params(modes, 1)[0];
-
-        self.output.push(input_param);
-    }
-
-    fn adjust_relative_base(&mut self, modes: &mut Vec<isize>) {
-        let input_param = self.get_input_params(modes, 1)[0];
-
-        self.relative_base += input_param;
-    }
-
-    fn jump_if_true(&mut self, modes: &mut Vec<isize>) {
-        let input_params = self.get_input_params(modes, 2);
-
-        if input_params[0] != 0 {
-            self.pointer = input_params[1];
-        }
-    }
-
-    fn jump_if_false(&mut self, modes: &mut Vec<isize>) {
-        let input_params = self.get_input_params(modes, 2);
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        path: String::from(DEFAULT_PATH),
+        format: Format::Text,
+        frames: None,
+        save: None,
+        restore: None,
+    };
 
-        if input_params[0] == 0 {
-            self.pointer = input_params[1];
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+
+        match arg.as_str() {
+            "--format" => options.format = value()?.parse()?,
+            "--frames" => {
+                let frames = value()?;
+                options.frames = Some(
+                    frames
+                        .parse()
+                        .map_err(|_| format!("{} is not a number of frames", frames))?,
+                );
+            }
+            "--save" => options.save = Some(value()?),
+            "--restore" => options.restore = Some(value()?),
+            _ if arg.starts_with("--") => return Err(format!("there is no {} option", arg)),
+            _ => options.path = arg,
         }
     }
 
-    fn less_than(&mut self, modes: &mut Vec<isize>) {
-        let input_params = self.get_input_params(modes, 2);
-        let output_mode = if modes.is_empty() { 0 } else { modes.remove(0) };
-        let output_param = self.get_output_param(output_mode);
-
-        self.program.insert(
-            output_param,
-            if input_params[0] < input_params[1] {
-                1
-            } else {
-                0
-            },
-        );
-    }
-
-    fn equals(&mut self, modes: &mut Vec<isize>) {
-        let input_params = self.get_input_params(modes, 2);
-        let output_mode = if modes.is_empty() { 0 } else { modes.remove(0) };
-        let output_param = self.get_output_param(output_mode);
-
-        self.program.insert(
-            output_param,
-            if input_params[0] == input_params[1] {
-                1
-            } else {
-                0
-            },
-        );
+    if options.format == Format::Json
+        && (options.frames.is_some() || options.save.is_some() || options.restore.is_some())
+    {
+        return Err(String::from(
+            "saving and restoring are for playing, which the json format doesn't do",
+        ));
     }
 
-    fn parse_instruction(instruction: isize) -> (isize, Vec<isize>) {
-        let opcode = instruction % 100;
-        let mut modes = Vec::new();
+    Ok(options)
+}
 
-        let mut modes_num = instruction / 100;
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
 
-        while modes_num > 0 {
-            modes.push(modes_num % 10);
-            modes_num /= 10;
+fn draw(screen: &Screen) {
+    print!("{}{}{}", clear::All, style::Reset, cursor::Goto(1, 1));
+
+    println!(
+        "SCORE: {}{}",
+        color::Fg(color::White),
+        screen.get(&(-1, 0)).cloned().unwrap_or(0)
+    );
+
+    let max_x = screen.keys().map(|v| v.0).max().unwrap_or(0);
+    let max_y = screen.keys().map(|v| v.1).max().unwrap_or(0);
+
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            match screen.get(&(x, y)).cloned().unwrap_or(0) {
+                1 => print!("{}█", color::Fg(color::Blue)),
+                2 => print!("{}▒", color::Fg(color::Red)),
+                3 => print!("{}—", color::Fg(color::White)),
+                4 => print!("{}o", color::Fg(color::White)),
+                _ => print!("{} ", color::Fg(color::Black)),
+            }
         }
 
-        (opcode, modes)
+        println!();
     }
 
-    fn compute(&mut self) {
-        loop {
-            let instruction = *self.program.entry(self.pointer).or_insert(0);
-            let (opcode, mut modes) = Self::parse_instruction(instruction);
-            self.pointer += 1;
-
-            match opcode {
-                0 => self.pointer = 0,
-                1 => self.add(&mut modes),
-                2 => self.multiply(&mut modes),
-                3 => {
-                    if self.input.is_empty() {
-                        self.pointer -= 1;
-                        break;
-                    } else {
-                        let input = self.input.remove(0);
-                        self.get_input(&mut modes, input)
-                    }
-                }
-                4 => self.calculate_output(&mut modes),
-                5 => self.jump_if_true(&mut modes),
-                6 => self.jump_if_false(&mut modes),
-                7 => self.less_than(&mut modes),
-                8 => self.equals(&mut modes),
-                9 => self.adjust_relative_base(&mut modes),
-                99 => {
-                    self.is_halted = true;
-                    break;
-                }
-                x => panic!("unknown operand! {}", x),
-            }
-        }
-    }
+    thread::sleep(time::Duration::from_millis(100));
 }
 
 fn main() {
-    let mut computer = Computer::init(&read_input(INPUT_PATH), Vec::new());
-    computer.compute();
-
-    loop {
-        let mut tiles_with_coords: HashMap<(isize, isize), isize> = HashMap::new();
-
-        let x_coords: Vec<isize> = computer
-            .output
-            .iter()
-            .enumerate()
-            .filter(|(i, &_o)| i % 3 == 0)
-            .map(|(_i, &o)| o)
-            .collect();
-        let y_coords: Vec<isize> = computer
-            .output
-            .iter()
-            .enumerate()
-            .filter(|(i, &_o)| i % 3 == 1)
-            .map(|(_i, &o)| o)
-            .collect();
-        let tiles: Vec<isize> = computer
-            .output
-            .iter()
-            .enumerate()
-            .filter(|(i, &_o)| i % 3 == 2)
-            .map(|(_i, &o)| o)
-            .collect();
-
-        for i in 0..x_coords.len() {
-            tiles_with_coords.insert((x_coords[i], y_coords[i]), tiles[i]);
-        }
-
-        let num_of_blocks = tiles_with_coords.values().filter(|&v| *v == 2).count();
-
-        write!(io::stdout(), "{}{}{}", clear::All, style::Reset, cursor::Goto(1, 1)).unwrap();
-
-        println!(
-            "SCORE: {}{}",
-            color::Fg(color::White),
-            tiles_with_coords.entry((-1, 0)).or_insert(0)
-        );
-
-        if num_of_blocks == 0 {
-            break;
+    let options =
+        parse_args(env::args().skip(1)).unwrap_or_else(|why| fail(format!("{}\n{}", why, USAGE)));
+
+    if options.frames.is_none() && options.save.is_none() && options.restore.is_none() {
+        let input = load_or_exit(&options.path);
+        let mut report = Report::init(13, options.format);
+        let computer = report.parse(|| parse(&input));
+
+        // Drawing the game clears the screen, so it is shown before the answers
+        // and part two is timed without it.
+        if options.format == Format::Text {
+            play(&computer, draw);
         }
 
-        let max_x = tiles_with_coords
-            .keys()
-            .map(|v| v.0)
-            .max()
-            .unwrap_or_else(|| 0);
-        let max_y = tiles_with_coords
-            .keys()
-            .map(|v| v.1)
-            .max()
-            .unwrap_or_else(|| 0);
-
-        let mut ball_x = 0;
-        let mut plank_x = 0;
-
-        for y in 0..=max_y {
-            for x in 0..=max_x {
-                let tile = tiles_with_coords.entry((x, y)).or_insert(0);
+        report.part(1, || part1(&computer));
+        report.part(2, || part2(&computer));
+        return;
+    }
 
-                match tile {
-                    1 => print!("{}{}", color::Fg(color::Blue), "█"),
-                    2 => print!("{}{}", color::Fg(color::Red), "▒"),
-                    3 => {
-                        plank_x = x;
-                        print!("{}{}", color::Fg(color::White), "—");
-                    }
-                    4 => {
-                        ball_x = x;
-                        print!("{}{}", color::Fg(color::White), "o");
-                    }
-                    _ => print!("{}{}", color::Fg(color::Black), " "),
-                }
-            }
+    let mut game = match &options.restore {
+        Some(path) => Computer::restore_snapshot(path)
+            .unwrap_or_else(|why| fail(format!("couldn't restore {}: {}", path, why))),
+        None => new_game(&parse(&load_or_exit(&options.path))),
+    };
 
-            println!();
+    let mut frames = 0;
+    let score = resume(&mut game, |screen| {
+        if options.frames == Some(frames) {
+            return false;
         }
 
-        let delay = time::Duration::from_millis(100);
-
-        thread::sleep(delay);
-
-        //        let mut input_command = String::new();
-        //
-        //        io::stdin()
-        //            .read_line(&mut input_command).expect("shit");
-        //
-        //        match input_command.trim().parse().unwrap() {
-        //            'a' => computer.input = vec![-1],
-        //            's' => computer.input = vec![0],
-        //            'd' => computer.input = vec![1],
-        //            x => panic!("wrong input: {}", x),
-        //        }
-
-        computer.input = vec![match ball_x - plank_x {
-            x if x < 0 => -1,
-            x if x > 0 => 1,
-            _ => 0,
-        }];
-
-        computer.compute();
+        draw(screen);
+        frames += 1;
+        true
+    });
+
+    match (score, &options.save) {
+        (Some(score), _) => println!("{}", score),
+        (None, Some(path)) => match game.save_snapshot(path) {
+            Ok(()) => println!("saved to {}", path),
+            Err(why) => fail(format!("couldn't save {}: {}", path, why)),
+        },
+        (None, None) => {}
     }
 }
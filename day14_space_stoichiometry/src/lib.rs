@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+#[derive(Eq, PartialEq, Hash, Debug, Clone)]
+struct Ingredient {
+    element: String,
+    quantity: isize,
+}
+
+#[derive(Eq, PartialEq, Hash, Debug, Clone)]
+pub struct Formula {
+    input: Vec<Ingredient>,
+    output: Ingredient,
+}
+
+pub type Input = HashMap<String, Formula>;
+
+pub fn parse(input: &str) -> Input {
+    let mut formulas: HashMap<String, Formula> = HashMap::new();
+
+    for formula in input.lines() {
+        let sides: Vec<&str> = formula.split(" => ").collect();
+
+        let mut input: Vec<Ingredient> = Vec::new();
+
+        for inputs in sides[0].trim().split(", ") {
+            let parameters: Vec<&str> = inputs.split(' ').collect();
+            let quantity = parameters[0].trim().parse::<isize>().unwrap();
+            let element = String::from(parameters[1].trim());
+
+            input.push(Ingredient { element, quantity });
+        }
+
+        let parameters: Vec<&str> = sides[1].trim().split(' ').collect();
+        let quantity = parameters[0].trim().parse::<isize>().unwrap();
+        let element = String::from(parameters[1].trim());
+
+        let output = Ingredient {
+            element: element.clone(),
+            quantity,
+        };
+
+        formulas.insert(
+            element.clone(),
+            Formula {
+                input: input.clone(),
+                output: output.clone(),
+            },
+        );
+    }
+
+    formulas
+}
+
+/// Runs reactions until nothing but ORE is owed in `elements_balance`.
+fn react(formulas: &Input, elements_balance: &mut HashMap<String, isize>) {
+    loop {
+        let mut ratio = 1;
+        let mut demand: Vec<Ingredient> = Vec::new();
+
+        for (element, balance) in elements_balance.iter_mut() {
+            if *balance >= 0 || element == "ORE" {
+                continue;
+            }
+
+            let formula = formulas.get(element).unwrap();
+
+            if balance.abs() % formula.output.quantity == 0 {
+                ratio = balance.abs() / formula.output.quantity;
+            } else {
+                ratio = balance.abs() / formula.output.quantity + 1;
+            }
+
+            *balance += formula.output.quantity * ratio;
+            demand = formula.input.clone();
+
+            break;
+        }
+
+        if demand.is_empty() {
+            break;
+        }
+
+        for ingredient in demand.iter() {
+            let supply = elements_balance
+                .entry(ingredient.element.clone())
+                .or_insert(0);
+            *supply -= ingredient.quantity * ratio;
+        }
+    }
+}
+
+/// The ORE needed to produce 1 FUEL.
+pub fn part1(formulas: &Input) -> isize {
+    let mut elements_balance: HashMap<String, isize> = HashMap::new();
+    elements_balance.insert("FUEL".to_string(), -1);
+
+    react(formulas, &mut elements_balance);
+
+    elements_balance.entry("ORE".to_string()).or_insert(0).abs()
+}
+
+/// The FUEL a trillion ORE can produce.
+pub fn part2(formulas: &Input) -> isize {
+    let mut ore_total: isize = 1_000_000_000_000;
+    let mut fuel_total: isize = 0;
+
+    let test_ore = part1(formulas);
+
+    let mut elements_balance: HashMap<String, isize> = HashMap::new();
+
+    loop {
+        elements_balance.insert("ORE".to_string(), 0);
+
+        let fuel_demand = if test_ore > ore_total {
+            1
+        } else {
+            ore_total / test_ore
+        };
+
+        elements_balance.insert("FUEL".to_string(), -fuel_demand);
+
+        react(formulas, &mut elements_balance);
+
+        let ore = elements_balance.entry("ORE".to_string()).or_insert(0).abs();
+
+        if ore > ore_total {
+            break;
+        }
+
+        fuel_total += fuel_demand;
+        ore_total -= ore;
+    }
+
+    fuel_total
+}
@@ -0,0 +1,19 @@
+mod maze;
+mod robot;
+
+use intcode::Computer;
+use robot::Robot;
+
+pub fn parse(input: &str) -> Computer {
+    Computer::init(input).unwrap_or_else(|why| panic!("couldn't load the program: {}", why))
+}
+
+/// The fewest movements to the oxygen system.
+pub fn part1(computer: &Computer) -> isize {
+    Robot::init(computer.clone()).find_shortest_path()
+}
+
+/// The minutes it takes oxygen to fill the area.
+pub fn part2(computer: &Computer) -> isize {
+    Robot::init(computer.clone()).oxygen_fill()
+}
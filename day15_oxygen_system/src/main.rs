@@ -1,16 +1,12 @@
-mod intcode;
-mod maze;
-mod read_input;
-mod robot;
-
-use read_input::*;
-use robot::Robot;
-
-const INPUT_PATH: &str = "input.txt";
+use day15_oxygen_system::{parse, part1, part2};
+use input::read_input;
+use report::report;
 
 fn main() {
-    let mut robot = Robot::init(&read_input(INPUT_PATH));
-    let dist = robot.oxygen_fill();
+    let input = read_input();
+    let mut report = report(15);
+    let computer = report.parse(|| parse(&input));
 
-    println!("{}", dist);
+    report.part(1, || part1(&computer));
+    report.part(2, || part2(&computer));
 }
@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use std::ops::Neg;
 
 #[derive(Eq, PartialEq, Hash, Clone, Debug, Copy)]
@@ -1,7 +1,9 @@
-use super::intcode::Computer;
+#![allow(dead_code)]
+
 use super::maze::CellType;
 use super::maze::Direction;
 use super::maze::*;
+use intcode::Computer;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 pub struct Robot {
@@ -12,9 +14,9 @@ pub struct Robot {
 }
 
 impl Robot {
-    pub fn init(program: &str) -> Robot {
+    pub fn init(computer: Computer) -> Robot {
         Robot {
-            computer: Computer::init(program, Vec::new()),
+            computer,
             maze: [((0, 0), CellType::Empty)].iter().cloned().collect(),
             position: (0, 0),
             direction: Direction::North,
@@ -22,10 +24,10 @@ impl Robot {
     }
 
     fn try_step(&mut self, direction: Direction) -> CellType {
-        self.computer.set_input(vec![direction as isize]);
-        self.computer.compute();
+        self.computer.provide_input(direction as isize);
+        self.computer.compute().unwrap();
 
-        let status = CellType::from(self.computer.read_output());
+        let status = CellType::from(self.computer.read_output().unwrap());
         self.maze
             .insert(find_neighbour_cell(self.position, direction), status);
 
@@ -133,7 +135,7 @@ impl Robot {
                 break;
             }
 
-            let previous_position = path.pop().unwrap_or_else(|| (0, 0));
+            let previous_position = path.pop().unwrap_or((0, 0));
 
             direction = match (
                 self.position.0 - previous_position.0,
@@ -248,7 +250,7 @@ impl Robot {
         let mut visited: HashSet<(isize, isize)> = HashSet::new();
         let mut queue: VecDeque<Node> = VecDeque::new();
 
-        visited.insert((oxygen_system_coords));
+        visited.insert(oxygen_system_coords);
         queue.push_back(Node {
             coordinates: (oxygen_system_coords),
             distance: 0,
@@ -266,60 +268,48 @@ impl Robot {
             let neighbour = find_neighbour_cell(node.coordinates, Direction::North);
 
             if !visited.contains(&neighbour) {
-                match self.maze.get(&neighbour) {
-                    Some(&CellType::Empty) => {
-                        queue.push_back(Node {
-                            coordinates: neighbour,
-                            distance: node.distance + 1,
-                        });
-                        visited.insert(neighbour);
-                    }
-                    _ => {}
+                if let Some(&CellType::Empty) = self.maze.get(&neighbour) {
+                    queue.push_back(Node {
+                        coordinates: neighbour,
+                        distance: node.distance + 1,
+                    });
+                    visited.insert(neighbour);
                 }
             }
 
             let neighbour = find_neighbour_cell(node.coordinates, Direction::South);
 
             if !visited.contains(&neighbour) {
-                match self.maze.get(&neighbour) {
-                    Some(&CellType::Empty) => {
-                        queue.push_back(Node {
-                            coordinates: neighbour,
-                            distance: node.distance + 1,
-                        });
-                        visited.insert(neighbour);
-                    }
-                    _ => {}
+                if let Some(&CellType::Empty) = self.maze.get(&neighbour) {
+                    queue.push_back(Node {
+                        coordinates: neighbour,
+                        distance: node.distance + 1,
+                    });
+                    visited.insert(neighbour);
                 }
             }
 
             let neighbour = find_neighbour_cell(node.coordinates, Direction::East);
 
             if !visited.contains(&neighbour) {
-                match self.maze.get(&neighbour) {
-                    Some(&CellType::Empty) => {
-                        queue.push_back(Node {
-                            coordinates: neighbour,
-                            distance: node.distance + 1,
-                        });
-                        visited.insert(neighbour);
-                    }
-                    _ => {}
+                if let Some(&CellType::Empty) = self.maze.get(&neighbour) {
+                    queue.push_back(Node {
+                        coordinates: neighbour,
+                        distance: node.distance + 1,
+                    });
+                    visited.insert(neighbour);
                 }
             }
 
             let neighbour = find_neighbour_cell(node.coordinates, Direction::West);
 
             if !visited.contains(&neighbour) {
-                match self.maze.get(&neighbour) {
-                    Some(&CellType::Empty) => {
-                        queue.push_back(Node {
-                            coordinates: neighbour,
-                            distance: node.distance + 1,
-                        });
-                        visited.insert(neighbour);
-                    }
-                    _ => {}
+                if let Some(&CellType::Empty) = self.maze.get(&neighbour) {
+                    queue.push_back(Node {
+                        coordinates: neighbour,
+                        distance: node.distance + 1,
+                    });
+                    visited.insert(neighbour);
                 }
             }
         }
@@ -328,10 +318,10 @@ impl Robot {
     }
 
     pub fn print_maze(&mut self) {
-        let min_x = self.maze.keys().map(|v| v.0).min().unwrap_or_else(|| 0);
-        let max_x = self.maze.keys().map(|v| v.0).max().unwrap_or_else(|| 0);
-        let min_y = self.maze.keys().map(|v| v.1).min().unwrap_or_else(|| 0);
-        let max_y = self.maze.keys().map(|v| v.1).max().unwrap_or_else(|| 0);
+        let min_x = self.maze.keys().map(|v| v.0).min().unwrap_or(0);
+        let max_x = self.maze.keys().map(|v| v.0).max().unwrap_or(0);
+        let min_y = self.maze.keys().map(|v| v.1).min().unwrap_or(0);
+        let max_y = self.maze.keys().map(|v| v.1).max().unwrap_or(0);
 
         for y in (min_y..=max_y).rev() {
             for x in min_x..=max_x {
@@ -0,0 +1,94 @@
+const Q: usize = 10_000;
+const OFFSET_SIZE: usize = 7;
+const PHASES: usize = 100;
+
+//((((n + 1) / k) as f64 * PI) / 2.0).sin() as isize)
+fn almost_sin(n: usize, k: usize) -> isize {
+    let base_pattern: [isize; 4] = [0, 1, 0, -1];
+    let period = base_pattern.len();
+
+    base_pattern[(n + 1) / (k + 1) % period]
+}
+
+fn message(signal: &[isize]) -> String {
+    signal
+        .iter()
+        .take(8)
+        .map(|digit| digit.to_string())
+        .collect()
+}
+
+pub type Input = Vec<isize>;
+
+pub fn parse(input: &str) -> Input {
+    let s = input.trim();
+
+    let mut signal: Vec<isize> = Vec::new();
+
+    for c in s.chars() {
+        signal.push(c.to_digit(10).unwrap() as isize);
+    }
+
+    signal
+}
+
+/// The first eight digits after `PHASES` phases of FFT.
+pub fn part1(signal: &[isize]) -> String {
+    let mut signal = signal.to_vec();
+
+    for _phase in 0..PHASES {
+        signal = (0..signal.len())
+            .map(|k| {
+                signal
+                    .iter()
+                    .enumerate()
+                    .skip(k)
+                    .map(|(n, &digit)| digit * almost_sin(n, k))
+                    .sum::<isize>()
+                    .abs()
+                    % 10
+            })
+            .collect();
+    }
+
+    message(&signal)
+}
+
+/// The eight digit message at the offset in the real signal.
+///
+/// Only works when the offset is in the second half of the real signal, as it
+/// is in every puzzle input: there each digit of a phase is just the sum of the
+/// digits after it, so the phases can be computed from the end backwards.
+pub fn part2(signal: &[isize]) -> String {
+    let mut signal = signal.to_vec();
+    let p = signal.len();
+
+    let offset = signal
+        .iter()
+        .take(OFFSET_SIZE)
+        .fold(0, |o: usize, &i| o * 10 + i as usize);
+
+    if offset * 2 < p * Q {
+        panic!(
+            "the message offset {} is in the first half of the {} digit signal, \
+             where digits don't just add up!",
+            offset,
+            p * Q
+        );
+    }
+
+    signal = signal
+        .into_iter()
+        .cycle()
+        .take(p * Q)
+        .skip(offset)
+        .collect();
+
+    for _phase in 0..PHASES {
+        for i in (0..signal.len() - 1).rev() {
+            signal[i] = (signal[i] + signal[i + 1]) % 10;
+        }
+    }
+
+    message(&signal)
+}
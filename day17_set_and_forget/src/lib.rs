@@ -0,0 +1,18 @@
+mod maze;
+mod robot;
+
+use intcode::Computer;
+use robot::Robot;
+
+pub fn parse(input: &str) -> Computer {
+    Computer::init(input).unwrap_or_else(|why| panic!("couldn't load the program: {}", why))
+}
+
+/// The sum of the alignment parameters of the scaffold intersections.
+pub fn part1(computer: &Computer) -> isize {
+    Robot::init(computer).find_intersections()
+}
+
+pub fn part2(computer: &Computer) -> isize {
+    Robot::init(computer).collect_dust()
+}
@@ -1,18 +1,12 @@
-mod intcode;
-mod maze;
-mod read_input;
-mod robot;
-
-use read_input::*;
-use robot::Robot;
-
-const INPUT_PATH: &str = "input.txt";
+use day17_set_and_forget::{parse, part1, part2};
+use input::read_input;
+use report::report;
 
 fn main() {
-    let mut robot = Robot::init(&read_input(INPUT_PATH));
-    robot.print_maze();
+    let input = read_input();
+    let mut report = report(17);
+    let computer = report.parse(|| parse(&input));
 
-//"R,12,L,8,L,4,L,4,L,8,R,6,L,6,R,12,L,8,L,4,L,4,L,8,R,6,L,6,L,8,L,4,R,12,L,6,L,4,R,12,L,8,L,4,L,4,L,8,L,4,R,12,L,6,L,4,R,12,L,8,L,4,L,4,L,8,L,4,R,12,L,6,L,4,L,8,R,6,L,6"
-    //        dbg!(robot.find_path());
-    //dbg!(robot.computer.get_output());
+    report.part(1, || part1(&computer));
+    report.part(2, || part2(&computer));
 }
@@ -1,6 +1,8 @@
-use super::intcode::Computer;
+#![allow(dead_code)]
+
 use super::maze::Direction;
 use super::maze::*;
+use intcode::Computer;
 use std::collections::{HashMap, HashSet};
 
 pub struct Robot {
@@ -11,7 +13,70 @@ pub struct Robot {
 }
 
 impl Robot {
-    pub fn init(program: &str) -> Robot {
+    /// Reads the scaffold from the cameras, without waking the robot up.
+    pub fn init(computer: &Computer) -> Robot {
+        let mut camera = computer.clone();
+        camera.write_memory(0, 1);
+        camera.run().unwrap();
+
+        let outputs = camera.read_outputs();
+
+        let mut x = 0;
+        let mut y = 0;
+        let mut position = (0, 0);
+        let mut direction = Direction::North;
+
+        let mut maze: HashMap<(isize, isize), char> = HashMap::new();
+
+        for output in outputs {
+            if output == 10 {
+                //carriage return
+                y += 1;
+                x = 0;
+            } else {
+                let c = std::char::from_u32(output as u32).unwrap();
+
+                match c {
+                    '^' => {
+                        position = (x, y);
+                        direction = Direction::North;
+                        maze.insert((x, y), '#');
+                    }
+                    'v' => {
+                        position = (x, y);
+                        direction = Direction::South;
+                        maze.insert((x, y), '#');
+                    }
+                    '>' => {
+                        position = (x, y);
+                        direction = Direction::East;
+                        maze.insert((x, y), '#');
+                    }
+                    '<' => {
+                        position = (x, y);
+                        direction = Direction::West;
+                        maze.insert((x, y), '#');
+                    }
+                    _ => {
+                        maze.insert((x, y), std::char::from_u32(output as u32).unwrap());
+                    }
+                }
+
+                x += 1;
+            }
+        }
+
+        Robot {
+            computer: computer.clone(),
+            maze,
+            position,
+            direction,
+        }
+    }
+
+    /// Wakes the robot up, walks it over the scaffold and returns the amount
+    /// of dust it collected.
+    pub fn collect_dust(&mut self) -> isize {
         let mut m = vec![
             //B,C,B,C,A,B,A,B,A,C
             'B' as isize,
@@ -100,62 +165,15 @@ impl Robot {
         input.append(&mut c);
         input.append(&mut n);
 
-        let mut computer = Computer::init(program, input);
-        computer.compute();
-
-        dbg!(computer.get_output());
-
-        let mut x = 0;
-        let mut y = 0;
-        let mut position = (0, 0);
-        let mut direction = Direction::North;
-
-        let mut maze: HashMap<(isize, isize), char> = HashMap::new();
-
-        for output in computer.get_output() {
-            if output == 10 {
-                //carriage return
-                y += 1;
-                x = 0;
-            } else {
-                let c = std::char::from_u32(output as u32).unwrap();
+        self.computer.write_memory(0, 2);
 
-                match c {
-                    '^' => {
-                        position = (x, y);
-                        direction = Direction::North;
-                        maze.insert((x, y), '#');
-                    }
-                    'v' => {
-                        position = (x, y);
-                        direction = Direction::South;
-                        maze.insert((x, y), '#');
-                    }
-                    '>' => {
-                        position = (x, y);
-                        direction = Direction::East;
-                        maze.insert((x, y), '#');
-                    }
-                    '<' => {
-                        position = (x, y);
-                        direction = Direction::West;
-                        maze.insert((x, y), '#');
-                    }
-                    _ => {
-                        maze.insert((x, y), std::char::from_u32(output as u32).unwrap());
-                    }
-                }
-
-                x += 1;
-            }
+        for i in input {
+            self.computer.provide_input(i);
         }
 
-        Robot {
-            computer,
-            maze,
-            position,
-            direction,
-        }
+        self.computer.run().unwrap();
+
+        *self.computer.read_outputs().last().unwrap()
     }
 
     fn try_step(&mut self, direction: Direction) -> bool {
@@ -260,8 +278,8 @@ impl Robot {
     }
 
     pub fn print_maze(&mut self) {
-        let max_x = self.maze.keys().map(|v| v.0).max().unwrap_or_else(|| 0);
-        let max_y = self.maze.keys().map(|v| v.1).max().unwrap_or_else(|| 0);
+        let max_x = self.maze.keys().map(|v| v.0).max().unwrap_or(0);
+        let max_y = self.maze.keys().map(|v| v.1).max().unwrap_or(0);
 
         for y in 0..=max_y {
             for x in 0..=max_x {
@@ -283,15 +301,15 @@ impl Robot {
             println!();
         }
 
-//        println!("Position: ({}, {})", self.position.0, self.position.1);
-//        println!(
-//            "Direction: {}",
-//            match self.direction {
-//                Direction::North => "North",
-//                Direction::South => "South",
-//                Direction::East => "East",
-//                Direction::West => "West",
-//            }
-//        );
+        //        println!("Position: ({}, {})", self.position.0, self.position.1);
+        //        println!(
+        //            "Direction: {}",
+        //            match self.direction {
+        //                Direction::North => "North",
+        //                Direction::South => "South",
+        //                Direction::East => "East",
+        //                Direction::West => "West",
+        //            }
+        //        );
     }
 }
@@ -1,7 +1,8 @@
+#![allow(dead_code)]
+
 use super::maze::CellType;
 use super::maze::Direction;
 use super::maze::*;
-use crate::read_input::read_input;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 type Point = (usize, usize);
@@ -18,8 +19,7 @@ pub struct Explorer {
 }
 
 impl Explorer {
-    pub fn init() -> Explorer {
-        let s = read_input();
+    pub fn init(s: &str) -> Explorer {
         let mut maze_map = HashMap::new();
         let mut keys = HashMap::new();
         let mut starting_positions = Vec::new();
@@ -189,12 +189,7 @@ impl Explorer {
         let keys: HashSet<char> = self
             .distances
             .keys()
-            .filter(|&(x, _)| {
-                *x == starting_coords[0]
-                    || *x == starting_coords[1]
-                    || *x == starting_coords[2]
-                    || *x == starting_coords[3]
-            })
+            .filter(|&(x, _)| starting_coords.contains(x))
             .map(|&(_, c)| c)
             .filter(|&k| !keys_collected.contains(&k))
             .filter(|&k| {
@@ -208,17 +203,10 @@ impl Explorer {
             .collect();
 
         for key in keys {
-            let mut j = 0;
-
-            if self.reachable_from(starting_coords[0]).contains(&key) {
-                j = 0;
-            } else if self.reachable_from(starting_coords[1]).contains(&key) {
-                j = 1;
-            } else if self.reachable_from(starting_coords[2]).contains(&key) {
-                j = 2;
-            } else if self.reachable_from(starting_coords[3]).contains(&key) {
-                j = 3;
-            }
+            let j = starting_coords
+                .iter()
+                .position(|&coords| self.reachable_from(coords).contains(&key))
+                .unwrap_or(0);
 
             let dist = *self.distances.get(&(starting_coords[j], key)).unwrap();
 
@@ -241,8 +229,7 @@ impl Explorer {
                 result = r;
             } else {
                 result = self.find_keys(key_coords.clone(), keys_collected);
-                self.found
-                    .insert((key_coords.clone(), keys_vector), result);
+                self.found.insert((key_coords.clone(), keys_vector), result);
             }
 
             if let Some(length) = result {
@@ -0,0 +1,76 @@
+mod explorer;
+mod maze;
+
+use explorer::Explorer;
+use std::collections::HashSet;
+
+pub type Input = Vec<Vec<char>>;
+
+pub fn parse(input: &str) -> Input {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+fn render(vault: &Input) -> String {
+    vault
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+fn entrances(vault: &Input) -> Vec<(usize, usize)> {
+    vault
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|&(_, &c)| c == '@')
+                .map(move |(x, _)| (x, y))
+        })
+        .collect()
+}
+
+/// The vault with the 3x3 block around its entrance, or around the middle of
+/// its four entrances, replaced by `centre`.
+fn with_centre(vault: &Input, centre: [&str; 3]) -> String {
+    let entrances = entrances(vault);
+    let x = entrances.iter().map(|e| e.0).sum::<usize>() / entrances.len();
+    let y = entrances.iter().map(|e| e.1).sum::<usize>() / entrances.len();
+
+    let mut vault = vault.clone();
+
+    for (dy, row) in centre.iter().enumerate() {
+        for (dx, c) in row.chars().enumerate() {
+            vault[y + dy - 1][x + dx - 1] = c;
+        }
+    }
+
+    render(&vault)
+}
+
+fn collect_keys(vault: &str) -> usize {
+    let mut explorer = Explorer::init(vault);
+
+    explorer
+        .find_keys(explorer.starting_positions.clone(), HashSet::new())
+        .unwrap()
+}
+
+/// The fewest steps to collect every key from a single entrance. A vault that
+/// is already split into quadrants, like the one in `input.txt`, is joined
+/// back together first.
+pub fn part1(vault: &Input) -> usize {
+    match entrances(vault).len() {
+        1 => collect_keys(&render(vault)),
+        _ => collect_keys(&with_centre(vault, ["...", ".@.", "..."])),
+    }
+}
+
+/// The fewest steps to collect every key with a robot in each quadrant. A vault
+/// with a single entrance is split into quadrants first.
+pub fn part2(vault: &Input) -> usize {
+    match entrances(vault).len() {
+        1 => collect_keys(&with_centre(vault, ["@#@", "###", "@#@"])),
+        _ => collect_keys(&render(vault)),
+    }
+}
@@ -1,20 +1,12 @@
-mod explorer;
-mod maze;
-mod read_input;
-
-use explorer::Explorer;
-use std::collections::HashSet;
-use std::time::SystemTime;
+use day18_many_worlds_interpretation::{parse, part1, part2};
+use input::read_input;
+use report::report;
 
 fn main() {
-    let earlier = SystemTime::now();
-
-    let mut explorer = Explorer::init();
-    //explorer.print_maze();
-    println!(
-        "{:?}",
-        explorer.find_keys(explorer.starting_positions.clone(), HashSet::new())
-    );
+    let input = read_input();
+    let mut report = report(18);
+    let vault = report.parse(|| parse(&input));
 
-    println!("{:?}", SystemTime::now().duration_since(earlier));
+    report.part(1, || part1(&vault));
+    report.part(2, || part2(&vault));
 }
@@ -0,0 +1,115 @@
+use day18_many_worlds_interpretation::{parse, part1, part2};
+
+#[test]
+fn part1_examples() {
+    let examples = [
+        (
+            "\
+#########
+#b.A.@.a#
+#########",
+            8,
+        ),
+        (
+            "\
+########################
+#f.D.E.e.C.b.A.@.a.B.c.#
+######################.#
+#d.....................#
+########################",
+            86,
+        ),
+        (
+            "\
+########################
+#...............b.C.D.f#
+#.######################
+#.....@.a.B.c.d.A.e.F.g#
+########################",
+            132,
+        ),
+        (
+            "\
+#################
+#i.G..c...e..H.p#
+########.########
+#j.A..b...f..D.o#
+########@########
+#k.E..a...g..B.n#
+########.########
+#l.F..d...h..C.m#
+#################",
+            136,
+        ),
+        (
+            "\
+########################
+#@..............ac.GI.b#
+###d#e#f################
+###A#B#C################
+###g#h#i################
+########################",
+            81,
+        ),
+    ];
+
+    for &(vault, steps) in examples.iter() {
+        assert_eq!(part1(&parse(vault)), steps, "\n{}", vault);
+    }
+}
+
+#[test]
+fn part2_examples() {
+    let examples = [
+        (
+            "\
+#######
+#a.#Cd#
+##...##
+##.@.##
+##...##
+#cB#Ab#
+#######",
+            8,
+        ),
+        (
+            "\
+###############
+#d.ABC.#.....a#
+######@#@######
+###############
+######@#@######
+#b.....#.....c#
+###############",
+            24,
+        ),
+        (
+            "\
+#############
+#DcBa.#.GhKl#
+#.###@#@#I###
+#e#d#####j#k#
+###C#@#@###J#
+#fEbA.#.FgHi#
+#############",
+            32,
+        ),
+        (
+            "\
+#############
+#g#f.D#..h#l#
+#F###e#E###.#
+#dCba@#@BcIJ#
+#############
+#nK.L@#@G...#
+#M###N#H###.#
+#o#m..#i#jk.#
+#############",
+            72,
+        ),
+    ];
+
+    for &(vault, steps) in examples.iter() {
+        assert_eq!(part2(&parse(vault)), steps, "\n{}", vault);
+    }
+}
@@ -0,0 +1,71 @@
+use intcode::Computer;
+
+fn calculate(computer: &mut Computer, x: isize, y: isize) -> isize {
+    computer.provide_input(x);
+    computer.provide_input(y);
+    computer.run().unwrap();
+    let output = computer.read_output().unwrap();
+    computer.reset();
+
+    output
+}
+
+pub fn parse(input: &str) -> Computer {
+    let mut computer =
+        Computer::init(input).unwrap_or_else(|why| panic!("couldn't load the program: {}", why));
+    computer.compile();
+
+    computer
+}
+
+/// The number of points affected by the beam in the 50x50 area nearest the
+/// emitter.
+pub fn part1(computer: &Computer) -> isize {
+    let mut computer = computer.clone();
+    let mut affected = 0;
+
+    for y in 0..50 {
+        for x in 0..50 {
+            affected += calculate(&mut computer, x, y);
+        }
+    }
+
+    affected
+}
+
+/// The closest 100x100 square that fits in the beam, as `x * 10000 + y`.
+pub fn part2(computer: &Computer) -> isize {
+    let mut computer = computer.clone();
+
+    calculate(&mut computer, 1, 1);
+
+    //    for y in 0..100 {
+    //        for x in 0..100 {
+    //            print!("{}", match calculate(&mut computer, x, y) {
+    //                0 => '.',
+    //                1 => '#',
+    //                _ => '?'
+    //            });
+    //        }
+    //        println!();
+    //    }
+
+    let mut x = 99;
+    let mut y = 0;
+
+    while calculate(&mut computer, x, y) == 0 {
+        y += 1;
+    }
+
+    loop {
+        while calculate(&mut computer, x, y) == 1 {
+            if calculate(&mut computer, x - 99, y + 99) == 1 {
+                return (x - 99) * 10000 + y;
+            }
+
+            x += 1;
+        }
+
+        y += 1;
+    }
+}
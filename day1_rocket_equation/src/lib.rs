@@ -0,0 +1,29 @@
+pub type Input = Vec<i32>;
+
+pub fn parse(input: &str) -> Input {
+    input
+        .lines()
+        .map(|module_mass| module_mass.parse().unwrap())
+        .collect()
+}
+
+pub fn part1(masses: &Input) -> i32 {
+    masses.iter().map(|&module_mass| module_mass / 3 - 2).sum()
+}
+
+pub fn part2(masses: &Input) -> i32 {
+    masses
+        .iter()
+        .map(|&module_mass| {
+            let mut fuel_mass: i32 = 0;
+            let mut fuel_delta: i32 = module_mass / 3 - 2;
+
+            while fuel_delta > 0 {
+                fuel_mass += fuel_delta;
+                fuel_delta = fuel_delta / 3 - 2;
+            }
+
+            fuel_mass
+        })
+        .sum::<i32>()
+}
@@ -1,7 +1,8 @@
+#![allow(dead_code)]
+
 use super::maze::CellType;
 use super::maze::Direction;
 use super::maze::*;
-use crate::read_input::read_input;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 pub struct Explorer {
@@ -12,8 +13,7 @@ pub struct Explorer {
 }
 
 impl Explorer {
-    pub fn init() -> Explorer {
-        let s = read_input();
+    pub fn init(s: &str) -> Explorer {
         let mut maze_map = HashMap::new();
 
         for (y, line) in s.lines().enumerate() {
@@ -24,8 +24,8 @@ impl Explorer {
 
         let mut portals = HashMap::new();
 
-        let max_x = maze_map.keys().map(|v| v.0).max().unwrap_or_else(|| 0);
-        let max_y = maze_map.keys().map(|v| v.1).max().unwrap_or_else(|| 0);
+        let max_x = maze_map.keys().map(|v| v.0).max().unwrap_or(0);
+        let max_y = maze_map.keys().map(|v| v.1).max().unwrap_or(0);
 
         for y in 0..=max_y {
             for x in 0..=max_x {
@@ -85,7 +85,9 @@ impl Explorer {
         }
     }
 
-    pub fn shortest_path(&self) -> Option<usize> {
+    /// The fewest steps from `AA` to `ZZ`. In a recursive maze the inner
+    /// portals lead a level down and the outer ones a level up.
+    pub fn shortest_path(&self, recursive: bool) -> Option<usize> {
         let mut visited_by_level: HashMap<usize, HashSet<Point>> =
             [(0, [self.start].iter().cloned().collect())]
                 .iter()
@@ -108,9 +110,7 @@ impl Explorer {
             ]
             .iter()
             {
-                let mut visited = visited_by_level
-                    .entry(node.level)
-                    .or_insert_with(HashSet::new);
+                let mut visited = visited_by_level.entry(node.level).or_default();
 
                 if !visited.contains(neighbour) {
                     match self.maze_map.get(neighbour) {
@@ -127,34 +127,40 @@ impl Explorer {
                             visited.insert(*neighbour);
                         }
                         Some(&CellType::StairDown(exit)) => {
+                            let level = if recursive {
+                                node.level + 1
+                            } else {
+                                node.level
+                            };
+
                             queue.push_back(Node {
                                 coordinates: exit,
                                 distance: node.distance + 2,
-                                level: node.level + 1,
+                                level,
                             });
                             visited.insert(*neighbour);
 
-                            visited = visited_by_level
-                                .entry(node.level + 1)
-                                .or_insert_with(HashSet::new);
+                            visited = visited_by_level.entry(level).or_default();
 
                             visited.insert(exit);
                         }
-                        Some(&CellType::StairUp(exit)) => {
-                            if node.level > 0 {
-                                queue.push_back(Node {
-                                    coordinates: exit,
-                                    distance: node.distance + 2,
-                                    level: node.level - 1,
-                                });
-                                visited.insert(*neighbour);
-
-                                visited = visited_by_level
-                                    .entry(node.level - 1)
-                                    .or_insert_with(HashSet::new);
-
-                                visited.insert(exit);
-                            }
+                        Some(&CellType::StairUp(exit)) if node.level > 0 || !recursive => {
+                            let level = if recursive {
+                                node.level - 1
+                            } else {
+                                node.level
+                            };
+
+                            queue.push_back(Node {
+                                coordinates: exit,
+                                distance: node.distance + 2,
+                                level,
+                            });
+                            visited.insert(*neighbour);
+
+                            visited = visited_by_level.entry(level).or_default();
+
+                            visited.insert(exit);
                         }
                         _ => {}
                     }
@@ -166,8 +172,8 @@ impl Explorer {
     }
 
     pub fn print_maze(&self) {
-        let max_x = self.maze_map.keys().map(|v| v.0).max().unwrap_or_else(|| 0);
-        let max_y = self.maze_map.keys().map(|v| v.1).max().unwrap_or_else(|| 0);
+        let max_x = self.maze_map.keys().map(|v| v.0).max().unwrap_or(0);
+        let max_y = self.maze_map.keys().map(|v| v.1).max().unwrap_or(0);
 
         for y in 0..=max_y {
             for x in 0..=max_x {
@@ -0,0 +1,16 @@
+mod explorer;
+mod maze;
+
+use explorer::Explorer;
+
+pub fn parse(input: &str) -> Explorer {
+    Explorer::init(input)
+}
+
+pub fn part1(explorer: &Explorer) -> usize {
+    explorer.shortest_path(false).unwrap()
+}
+
+pub fn part2(explorer: &Explorer) -> usize {
+    explorer.shortest_path(true).unwrap()
+}
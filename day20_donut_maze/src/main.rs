@@ -1,13 +1,12 @@
-mod explorer;
-mod maze;
-mod read_input;
-
-use explorer::Explorer;
-use std::collections::HashSet;
+use day20_donut_maze::{parse, part1, part2};
+use input::read_input;
+use report::report;
 
 fn main() {
-    let mut explorer = Explorer::init();
-    explorer.print_maze();
-    println!("{:?}", &explorer.portals);
-    println!("{:?}", &explorer.shortest_path());
+    let input = read_input();
+    let mut report = report(20);
+    let explorer = report.parse(|| parse(&input));
+
+    report.part(1, || part1(&explorer));
+    report.part(2, || part2(&explorer));
 }
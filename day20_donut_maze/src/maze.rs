@@ -1,4 +1,3 @@
-use std::collections::HashSet;
 use std::ops::Neg;
 
 pub type Point = (usize, usize);
@@ -16,7 +15,7 @@ impl From<char> for CellType {
     fn from(status: char) -> CellType {
         match status {
             '#' => CellType::Wall,
-            c if c == '.' => CellType::Empty,
+            '.' => CellType::Empty,
             c if c.is_ascii_uppercase() || c == ' ' => CellType::Nothing(c),
             _ => panic!("Not a valid cell type!"),
         }
@@ -0,0 +1,72 @@
+use intcode::{Budget, Computer};
+
+/// Far more than a working springscript program needs, so a broken one ends
+/// with an error instead of running forever.
+const BUDGET: Budget = Budget {
+    instructions: Some(100_000_000),
+    memory: Some(65_536),
+    output: Some(10_000),
+};
+
+fn get_walk_program() -> String {
+    let mut input = String::new();
+    input.push_str("NOT A J\n");
+    input.push_str("NOT B T\n");
+    input.push_str("OR T J\n");
+    input.push_str("NOT C T\n");
+    input.push_str("OR T J\n");
+    input.push_str("AND D J\n");
+    input.push_str("WALK\n");
+
+    input
+}
+
+fn get_run_program() -> String {
+    let mut input = String::new();
+    input.push_str("NOT A J\n");
+    input.push_str("NOT B T\n");
+    input.push_str("OR T J\n");
+    input.push_str("NOT C T\n");
+    input.push_str("OR T J\n");
+    input.push_str("AND D J\n");
+    input.push_str("NOT E T\n");
+    input.push_str("NOT T T\n");
+    input.push_str("OR H T\n");
+    input.push_str("AND T J\n");
+    input.push_str("RUN\n");
+
+    input
+}
+
+/// The hull damage the springdroid reports once it makes it across.
+fn survey(computer: &Computer, program: &str) -> isize {
+    let mut computer = computer.clone();
+    computer.set_budget(BUDGET);
+    computer.provide_ascii_input(program);
+
+    if let Err(why) = computer.run() {
+        panic!("springdroid program failed: {}", why);
+    }
+
+    let outputs = computer.read_outputs();
+
+    match outputs.last() {
+        Some(&damage) if damage > 127 => damage,
+        _ => panic!(
+            "springdroid fell into space:\n{}",
+            outputs.iter().map(|&o| o as u8 as char).collect::<String>()
+        ),
+    }
+}
+
+pub fn parse(input: &str) -> Computer {
+    Computer::init(input).unwrap_or_else(|why| panic!("couldn't load the program: {}", why))
+}
+
+pub fn part1(computer: &Computer) -> isize {
+    survey(computer, &get_walk_program())
+}
+
+pub fn part2(computer: &Computer) -> isize {
+    survey(computer, &get_run_program())
+}
@@ -0,0 +1,51 @@
+mod magic_card;
+mod shuffle_process;
+
+use crate::magic_card::MagicCard;
+use crate::shuffle_process::Process;
+use modinverse::*;
+
+fn run_many(a: i128, b: i128, e: isize, l: i128) -> (i128, i128) {
+    if e == 1 {
+        (a, b)
+    } else if e % 2 == 0 {
+        run_many((a * a) % l, (a * b + b) % l, e / 2, l)
+    } else {
+        let (c, d) = run_many(a, b, e - 1, l);
+        ((a * c) % l, (a * d + b) % l)
+    }
+}
+
+pub fn parse(input: &str) -> Process {
+    Process::init(input)
+}
+
+/// The position of card 2019 in a factory order deck of 10007 cards after
+/// one shuffle.
+pub fn part1(process: &Process) -> isize {
+    MagicCard::init(2019, 10007).complete_process(process)
+}
+
+/// The card that ends up in position 2020 after shuffling a huge deck many
+/// times over.
+pub fn part2(process: &Process) -> i128 {
+    let card = MagicCard::init(2019, 119_315_717_514_047);
+    //    let mut card = MagicCard::init(2020, 119_315_717_514_047);
+
+    let quick_process = card.quck_process(process);
+
+    //    let a = modinverse(quick_process.0, card.deck_size).unwrap() as i128;
+    let a = quick_process.0 as i128;
+    let b = quick_process.1 as i128;
+    let l = card.deck_size as i128;
+
+    //    for i in 1..101_741_582_076_661 {
+    //        result = (result + b) * a % l;
+    //    }
+
+    let (a, b) = run_many(a, b, 101_741_582_076_661, l);
+
+    let mi_a = modinverse(a, card.deck_size as i128).unwrap();
+
+    (2020 - b) * mi_a % l + l
+}
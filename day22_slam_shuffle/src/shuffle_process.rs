@@ -1,7 +1,5 @@
 #![allow(dead_code)]
 
-use crate::read_input::read_input;
-
 #[derive(Debug)]
 pub enum Technique {
     DealIntoNewStack,
@@ -15,8 +13,7 @@ pub struct Process {
 }
 
 impl Process {
-    pub fn init() -> Process {
-        let input = read_input();
+    pub fn init(input: &str) -> Process {
         let mut techniques = Vec::new();
 
         for line in input.lines() {
@@ -0,0 +1,80 @@
+mod nat;
+mod nic;
+
+use crate::nat::Nat;
+use futures::channel::mpsc;
+use futures::channel::mpsc::UnboundedSender;
+use futures::executor::LocalPool;
+use futures::task::LocalSpawnExt;
+use intcode::Computer;
+use nic::{Inbox, Outbox};
+use std::rc::Rc;
+
+/// Boots the network and runs it until `watch`, called with the NAT and the
+/// route to address 0 whenever the network is idle, returns a value.
+fn boot(
+    computer: &Computer,
+    mut watch: impl FnMut(&mut Nat, &UnboundedSender<isize>) -> Option<isize>,
+) -> isize {
+    let mut nat = Nat::init();
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+
+    let (routes, inboxes): (Vec<_>, Vec<_>) = (0..50).map(|_| mpsc::unbounded()).unzip();
+    let routes = Rc::new(routes);
+
+    for (i, receiver) in inboxes.into_iter().enumerate() {
+        let inbox = Inbox::init(receiver);
+        let outbox = Outbox::init(routes.clone(), nat.mailbox());
+
+        spawner
+            .spawn_local(nic::run(computer.clone(), i as isize, inbox, outbox))
+            .unwrap();
+    }
+
+    // Every NIC is blocked on an empty inbox whenever the pool stalls, so
+    // only a packet the NAT received since the last stall can wake it up.
+    let mut received = 0;
+
+    loop {
+        pool.run_until_stalled();
+
+        if let Some(y) = watch(&mut nat, &routes[0]) {
+            return y;
+        }
+
+        if nat.received() == received {
+            panic!("the network went quiet without sending the NAT anything!");
+        }
+
+        received = nat.received();
+    }
+}
+
+pub fn parse(input: &str) -> Computer {
+    Computer::init(input).unwrap_or_else(|why| panic!("couldn't load the program: {}", why))
+}
+
+/// The Y value of the first packet sent to address 255.
+pub fn part1(computer: &Computer) -> isize {
+    boot(computer, |nat, _| nat.first_y())
+}
+
+/// The first Y value the NAT delivers to address 0 twice in a row.
+pub fn part2(computer: &Computer) -> isize {
+    part2_with_diagnostics(computer, &mut Vec::new())
+}
+
+/// Like `part2`, noting every Y value the NAT delivers in `diagnostics`.
+pub fn part2_with_diagnostics(computer: &Computer, diagnostics: &mut Vec<String>) -> isize {
+    boot(computer, |nat, route| {
+        let y = nat.wake_up(route)?;
+        diagnostics.push(format!("Y sent: {}", y));
+
+        if nat.repeated() {
+            Some(y)
+        } else {
+            None
+        }
+    })
+}
@@ -1,85 +1,72 @@
-use std::collections::{HashMap, VecDeque};
-use std::sync::{Arc, Mutex};
-use std::thread;
-use std::time::Duration;
+use futures::channel::mpsc::UnboundedSender;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Packets sent to address 255: the first one ever, the latest one and how
+/// many there have been.
+#[derive(Default)]
+pub struct Mailbox {
+    first: Option<(isize, isize)>,
+    last: Option<(isize, isize)>,
+    received: usize,
+}
+
+impl Mailbox {
+    pub fn receive(&mut self, packet: (isize, isize)) {
+        if self.first.is_none() {
+            self.first = Some(packet);
+        }
+
+        self.last = Some(packet);
+        self.received += 1;
+    }
+}
 
 pub struct Nat {
-    address: isize,
-    router: Arc<Mutex<HashMap<isize, VecDeque<isize>>>>,
-    idles: Arc<Mutex<HashMap<isize, bool>>>,
-    process_finished: Arc<Mutex<bool>>,
+    packet: Rc<RefCell<Mailbox>>,
     last_sent_y: Option<isize>,
+    repeated: bool,
 }
 
 impl Nat {
-    pub fn init(
-        router: Arc<Mutex<HashMap<isize, VecDeque<isize>>>>,
-        idles: Arc<Mutex<HashMap<isize, bool>>>,
-        process_finished: Arc<Mutex<bool>>,
-    ) -> Nat {
+    pub fn init() -> Nat {
         Nat {
-            address: 255,
-            router,
-            idles,
-            process_finished,
+            packet: Rc::new(RefCell::new(Mailbox::default())),
             last_sent_y: None,
+            repeated: false,
         }
     }
 
-    pub fn is_network_idle(&self) -> bool {
-        let mut network_is_idle = true;
-
-        {
-            let idles = &mut *self.idles.lock().unwrap();
-            let router = &mut *self.router.lock().unwrap();
-
-            for i in 0..50 {
-                let idle = idles.entry(i).or_insert(false);
-                network_is_idle = network_is_idle && *idle;
-            }
-
-            for i in 0..50 {
-                let inputs = router.entry(i).or_insert_with(VecDeque::new);
-                network_is_idle = network_is_idle && inputs.is_empty();
-            }
-        }
-
-        network_is_idle
+    pub fn mailbox(&self) -> Rc<RefCell<Mailbox>> {
+        self.packet.clone()
     }
 
-    pub fn monitor(&mut self) {
-        loop {
-            if self.is_network_idle() {
-                let router = &mut *self.router.lock().unwrap();
-                let inputs = router.entry(self.address).or_insert_with(VecDeque::new);
-
-                let y = inputs.pop_front().unwrap();
-                let x = inputs.pop_front().unwrap();
-                inputs.clear();
+    /// The Y value of the first packet sent to the NAT.
+    pub fn first_y(&self) -> Option<isize> {
+        self.packet.borrow().first.map(|(_, y)| y)
+    }
 
-                let dest_inputs = router.entry(0).or_insert_with(VecDeque::new);
+    /// The number of packets sent to the NAT so far.
+    pub fn received(&self) -> usize {
+        self.packet.borrow().received
+    }
 
-                dest_inputs.push_front(x);
-                dest_inputs.push_front(y);
+    /// Whether the last two packets sent to address 0 had the same Y value.
+    pub fn repeated(&self) -> bool {
+        self.repeated
+    }
 
-                println!("last Y sent: {:?}", &self.last_sent_y);
-                println!("Y sent: {}", y);
+    /// Sends the last packet to address 0 once the network is idle and
+    /// returns its Y value, or `None` if no packet has reached the NAT yet.
+    pub fn wake_up(&mut self, route: &UnboundedSender<isize>) -> Option<isize> {
+        let (x, y) = self.packet.borrow_mut().last.take()?;
 
-                if let Some(last_y) = self.last_sent_y {
-                    if last_y == y {
-                        println!("Second y: {}", y);
+        route.unbounded_send(x).unwrap();
+        route.unbounded_send(y).unwrap();
 
-                        let process_finished = &mut *self.process_finished.lock().unwrap();
-                        *process_finished = true;
+        self.repeated = self.last_sent_y == Some(y);
+        self.last_sent_y = Some(y);
 
-                        break;
-                    } else {
-                        self.last_sent_y = Some(y);
-                    }
-                } else {
-                    self.last_sent_y = Some(y);
-                }
-            }
-        }
+        Some(y)
     }
 }
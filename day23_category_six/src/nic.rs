@@ -1,85 +1,100 @@
-use crate::intcode::{Computer, State};
-use std::collections::{HashMap, VecDeque};
-use std::sync::{Arc, Mutex};
+use crate::nat::Mailbox;
+use futures::channel::mpsc::{UnboundedReceiver, UnboundedSender};
+use futures::task::{Context, Poll};
+use futures::{Sink, Stream, StreamExt};
+use intcode::Computer;
+use std::cell::RefCell;
+use std::pin::Pin;
+use std::rc::Rc;
 
-pub struct Nic {
-    address: isize,
-    computer: Computer,
-    router: Arc<Mutex<HashMap<isize, VecDeque<isize>>>>,
-    idles: Arc<Mutex<HashMap<isize, bool>>>,
-    process_finished: Arc<Mutex<bool>>,
-    packet: VecDeque<isize>,
+/// Packets waiting for a NIC. When there are none it hands out a single -1
+/// and then blocks until the next packet arrives, instead of making the NIC
+/// spin on -1.
+pub struct Inbox {
+    receiver: UnboundedReceiver<isize>,
+    idle: bool,
 }
 
-impl Nic {
-    pub fn init(
-        address: isize,
-        mut computer: Computer,
-        router: Arc<Mutex<HashMap<isize, VecDeque<isize>>>>,
-        idles: Arc<Mutex<HashMap<isize, bool>>>,
-        process_finished: Arc<Mutex<bool>>,
-    ) -> Nic {
-        computer.provide_input(address);
-
-        Nic {
-            address,
-            computer,
-            router,
-            idles,
-            process_finished,
-            packet: VecDeque::new(),
+impl Inbox {
+    pub fn init(receiver: UnboundedReceiver<isize>) -> Inbox {
+        Inbox {
+            receiver,
+            idle: false,
         }
     }
+}
 
-    pub fn generate_packets(&mut self) {
-        let mut state = self.computer.compute();
+impl Stream for Inbox {
+    type Item = isize;
 
-        while state != State::Finished {
-            {
-                let process_finished = &mut *self.process_finished.lock().unwrap();
-                if *process_finished {
-                    break;
-                }
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<isize>> {
+        match self.receiver.poll_next_unpin(cx) {
+            Poll::Ready(value) => {
+                self.idle = false;
+                Poll::Ready(value)
+            }
+            Poll::Pending if !self.idle => {
+                self.idle = true;
+                Poll::Ready(Some(-1))
             }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
 
-            match state {
-                State::AwaitingInput => {
-                    let idles = &mut *self.idles.lock().unwrap();
-                    let idle = idles.entry(self.address).or_insert(false);
+/// Collects output into packets and routes them to the other NICs or the NAT.
+pub struct Outbox {
+    routes: Rc<Vec<UnboundedSender<isize>>>,
+    nat: Rc<RefCell<Mailbox>>,
+    packet: Vec<isize>,
+}
 
-                    let router = &mut *self.router.lock().unwrap();
-                    let inputs = router.entry(self.address).or_insert_with(VecDeque::new);
+impl Outbox {
+    pub fn init(routes: Rc<Vec<UnboundedSender<isize>>>, nat: Rc<RefCell<Mailbox>>) -> Outbox {
+        Outbox {
+            routes,
+            nat,
+            packet: Vec::new(),
+        }
+    }
+}
 
-                    if inputs.is_empty() {
-                        *idle = true;
-                        self.computer.provide_input(-1);
-                    } else {
-                        *idle = false;
-                        while !inputs.is_empty() {
-                            self.computer.provide_input(inputs.pop_back().unwrap());
-                        }
-                    }
-                }
-                State::ProducedOutput => {
-                    self.packet.push_front(self.computer.read_output().unwrap());
+impl Sink<isize> for Outbox {
+    type Error = ();
 
-                    if self.packet.len() == 3 {
-                        let router = &mut *self.router.lock().unwrap();
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), ()>> {
+        Poll::Ready(Ok(()))
+    }
 
-                        let dest_address = self.packet.pop_back().unwrap();
-                        let x = self.packet.pop_back().unwrap();
-                        let y = self.packet.pop_back().unwrap();
+    fn start_send(mut self: Pin<&mut Self>, value: isize) -> Result<(), ()> {
+        self.packet.push(value);
 
-                        let inputs = router.entry(dest_address).or_insert_with(VecDeque::new);
+        if self.packet.len() == 3 {
+            let (dest_address, x, y) = (self.packet[0], self.packet[1], self.packet[2]);
+            self.packet.clear();
 
-                        inputs.push_front(x);
-                        inputs.push_front(y);
-                    }
-                }
-                _ => panic!("Wrong state!"),
+            if dest_address == 255 {
+                self.nat.borrow_mut().receive((x, y));
+            } else {
+                let route = &self.routes[dest_address as usize];
+                route.unbounded_send(x).unwrap();
+                route.unbounded_send(y).unwrap();
             }
-
-            state = self.computer.compute();
         }
+
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), ()>> {
+        Poll::Ready(Ok(()))
     }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), ()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+pub async fn run(mut computer: Computer, address: isize, inbox: Inbox, outbox: Outbox) {
+    computer.provide_input(address);
+    computer.compute_async(inbox, outbox).await.unwrap();
 }
@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use std::collections::HashMap;
 use std::fmt::{Display, Error, Formatter};
 
@@ -7,9 +9,10 @@ pub struct Point {
     pub y: usize,
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default)]
 pub enum CellType {
     Bug,
+    #[default]
     Empty,
     LevelBelow,
 }
@@ -22,20 +25,19 @@ pub enum Edge {
     Right,
 }
 
-impl Default for CellType {
-    fn default() -> Self {
-        CellType::Empty
-    }
-}
-
 pub struct Eris {
     pub levels: HashMap<i32, Level>,
 }
 
 impl Eris {
-    pub fn init(s: &str) -> Eris {
+    pub fn init(level: &Level) -> Eris {
+        let mut level = level.clone();
+        level
+            .area
+            .insert(Point { x: 2, y: 2 }, CellType::LevelBelow);
+
         Eris {
-            levels: [(0, Level::init(s))].iter().cloned().collect(),
+            levels: [(0, level)].iter().cloned().collect(),
         }
     }
 
@@ -215,6 +217,17 @@ impl Default for Level {
 
 impl Level {
     pub fn init(s: &str) -> Level {
+        let mut level = Level::flat(s);
+        level
+            .area
+            .insert(Point { x: 2, y: 2 }, CellType::LevelBelow);
+
+        level
+    }
+
+    /// A level without a level below it, where the middle tile is like any
+    /// other.
+    pub fn flat(s: &str) -> Level {
         let mut area = HashMap::new();
 
         let lines = s.lines();
@@ -234,11 +247,37 @@ impl Level {
             }
         }
 
-        area.insert(Point { x: 2, y: 2 }, CellType::LevelBelow);
-
         Level { area }
     }
 
+    /// The flat level a minute later.
+    pub fn pass_a_minute(&self) -> Level {
+        let mut new_area = HashMap::new();
+
+        for (p, ct) in self.area.iter() {
+            let count = [(0, 1), (2, 1), (1, 0), (1, 2)]
+                .iter()
+                .filter_map(|&(dx, dy)| {
+                    self.area.get(&Point {
+                        x: (p.x + dx).checked_sub(1)?,
+                        y: (p.y + dy).checked_sub(1)?,
+                    })
+                })
+                .filter(|&&ct| ct == CellType::Bug)
+                .count();
+
+            let cell_type = match *ct {
+                CellType::Bug if count != 1 => CellType::Empty,
+                CellType::Empty if count == 1 || count == 2 => CellType::Bug,
+                ct => ct,
+            };
+
+            new_area.insert(*p, cell_type);
+        }
+
+        Level { area: new_area }
+    }
+
     pub fn biodiversity_rating(&self) -> usize {
         self.area
             .iter()
@@ -0,0 +1,31 @@
+use crate::eris::{Eris, Level};
+use std::collections::HashSet;
+
+mod eris;
+
+pub fn parse(input: &str) -> Level {
+    Level::flat(input)
+}
+
+/// The biodiversity rating of the first layout that appears twice.
+pub fn part1(level: &Level) -> usize {
+    let mut level = level.clone();
+    let mut ratings = HashSet::new();
+
+    while ratings.insert(level.biodiversity_rating()) {
+        level = level.pass_a_minute();
+    }
+
+    level.biodiversity_rating()
+}
+
+/// The number of bugs in the recursive grid after 200 minutes.
+pub fn part2(level: &Level) -> usize {
+    let mut eris = Eris::init(level);
+
+    for _ in 1..=200 {
+        eris.pass_a_minute();
+    }
+
+    eris.count_bugs()
+}
@@ -1,16 +1,12 @@
-use crate::eris::Eris;
-use read_input::*;
-
-mod eris;
-mod read_input;
+use day24_planet_of_discord::{parse, part1, part2};
+use input::read_input;
+use report::report;
 
 fn main() {
-    let mut eris = Eris::init(&read_input());
-
-    for _ in 1..=200 {
-        eris.pass_a_minute();
-    }
+    let input = read_input();
+    let mut report = report(24);
+    let level = report.parse(|| parse(&input));
 
-    println!("{}", eris.count_bugs());
-//    println!("{}", eris);
+    report.part(1, || part1(&level));
+    report.part(2, || part2(&level));
 }
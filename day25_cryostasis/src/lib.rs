@@ -0,0 +1,151 @@
+use intcode::{Computer, State};
+use std::collections::HashSet;
+
+/// Picks up the safe items and walks to the security checkpoint, stopping
+/// before the pressure-sensitive floor. `show` sees everything the droid
+/// prints.
+pub fn walk_to_checkpoint(computer: &Computer, mut show: impl FnMut(&str)) -> Computer {
+    let mut computer = computer.clone();
+    computer.compute().unwrap();
+
+    show(&computer.read_ascii_output());
+
+    let commands = [
+        "east",
+        "take klein bottle",
+        "east",
+        "take semiconductor",
+        "west",
+        "north",
+        "north",
+        "north",
+        "take dehydrated water",
+        "south",
+        "south",
+        "south",
+        "west",
+        "north",
+        "take sand",
+        "north",
+        "north",
+        "take astrolabe",
+        "south",
+        "south",
+        "west",
+        "west",
+        "take mutex",
+        "east",
+        "east",
+        "south",
+        "west",
+        "north",
+        "take shell",
+        "south",
+        "south",
+        "west",
+        "take ornament",
+        "west",
+        "south",
+    ];
+
+    for &command in commands.iter() {
+        let mut command = String::from(command);
+        command.push('\n');
+
+        computer.provide_ascii_input(&command);
+        computer.compute().unwrap();
+
+        show(&computer.read_ascii_output());
+    }
+
+    computer
+}
+
+/// Walks to the security checkpoint and tries item combinations until the
+/// floor lets the droid through. `show` sees everything the droid prints.
+pub fn reach_checkpoint(computer: &Computer, mut show: impl FnMut(&str)) -> (Computer, State) {
+    let mut computer = walk_to_checkpoint(computer, &mut show);
+    let mut result = State::AwaitingInput;
+
+    let items = [
+        "mutex",
+        "ornament",
+        "astrolabe",
+        "sand",
+        "semiconductor",
+        "dehydrated water",
+        "shell",
+        "klein bottle",
+    ];
+
+    let mut inv: HashSet<&str> = items.iter().cloned().collect();
+
+    let alerts = [
+        "Droids on this ship are lighter than the detected value!",
+        "Droids on this ship are heavier than the detected value!",
+    ];
+
+    for i in (0..2_i32.pow(8)).rev() {
+        for (k, item) in items.iter().enumerate().take(8_usize) {
+            let j = i / 2_i32.pow(k as u32) % 2;
+
+            if j == 1 && !inv.contains(item) {
+                let mut instruction = String::from("take ");
+                instruction.push_str(item);
+                instruction.push('\n');
+
+                computer.provide_ascii_input(&instruction);
+                computer.compute().unwrap();
+                show(&computer.read_ascii_output());
+
+                inv.insert(item);
+            }
+
+            if j == 0 && inv.contains(item) {
+                let mut instruction = String::from("drop ");
+                instruction.push_str(item);
+                instruction.push('\n');
+
+                computer.provide_ascii_input(&instruction);
+                computer.compute().unwrap();
+                show(&computer.read_ascii_output());
+
+                inv.remove(item);
+            }
+        }
+
+        let instruction = String::from("south\n");
+
+        computer.provide_ascii_input(&instruction);
+        result = computer.compute().unwrap();
+
+        let output = computer.read_ascii_output();
+        show(&output);
+
+        if !output.contains(alerts[0]) && !output.contains(alerts[1]) {
+            break;
+        }
+    }
+
+    (computer, result)
+}
+
+pub fn parse(input: &str) -> Computer {
+    let mut computer =
+        Computer::init(input).unwrap_or_else(|why| panic!("couldn't load the program: {}", why));
+    computer.compile();
+
+    computer
+}
+
+/// The airlock password Santa radios once the droid is through.
+pub fn part1(computer: &Computer) -> String {
+    let mut log = String::new();
+    reach_checkpoint(computer, |output| log.push_str(output));
+
+    log.split("by typing ")
+        .nth(1)
+        .and_then(|rest| rest.split_whitespace().next())
+        .unwrap_or_else(|| panic!("the droid never got through the checkpoint!"))
+        .to_string()
+}
@@ -1,134 +1,136 @@
-use crate::intcode::{Computer, State};
-use crate::read_input::*;
-use std::collections::HashSet;
+use day25_cryostasis::{parse, part1, reach_checkpoint, walk_to_checkpoint};
+use input::{load_or_exit, DEFAULT_PATH};
+use intcode::{Computer, State};
+use report::{Format, Report};
+use std::env;
 use std::io;
+use std::process;
+
+const USAGE: &str = "\
+arguments: [input] [--format text|json] [--checkpoint] [--restore <snapshot>]
+    [input]               the puzzle input, input.txt by default
+    --format <name>       text, the default, lets you play on once the droid is
+                          through; json just prints the password with timings
+    --checkpoint          stop at the security checkpoint instead of trying item
+                          combinations, to play or save from there
+    --restore <snapshot>  play on from a saved game, the input isn't read
+
+While playing, save <path> and load <path> save and restore the game.";
+
+struct Options {
+    path: String,
+    format: Format,
+    checkpoint: bool,
+    restore: Option<String>,
+}
 
-mod intcode;
-mod read_input;
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        path: String::from(DEFAULT_PATH),
+        format: Format::Text,
+        checkpoint: false,
+        restore: None,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+
+        match arg.as_str() {
+            "--format" => options.format = value()?.parse()?,
+            "--checkpoint" => options.checkpoint = true,
+            "--restore" => options.restore = Some(value()?),
+            _ if arg.starts_with("--") => return Err(format!("there is no {} option", arg)),
+            _ => options.path = arg,
+        }
+    }
 
-fn main() {
-    let mut computer = Computer::init(&read_input());
-    let mut result = computer.compute();
+    if options.format == Format::Json && (options.checkpoint || options.restore.is_some()) {
+        return Err(String::from(
+            "--checkpoint and --restore are for playing on, which the json format doesn't do",
+        ));
+    }
 
-    println!("{}", computer.read_ascii_output());
+    Ok(options)
+}
 
-    let commands = [
-        "east",
-        "take klein bottle",
-        "east",
-        "take semiconductor",
-        "west",
-        "north",
-        "north",
-        "north",
-        "take dehydrated water",
-        "south",
-        "south",
-        "south",
-        "west",
-        "north",
-        "take sand",
-        "north",
-        "north",
-        "take astrolabe",
-        "south",
-        "south",
-        "west",
-        "west",
-        "take mutex",
-        "east",
-        "east",
-        "south",
-        "west",
-        "north",
-        "take shell",
-        "south",
-        "south",
-        "west",
-        "take ornament",
-        "west",
-        "south",
-    ];
-
-    for &command in commands.iter() {
-        let mut command = String::from(command);
-        command.push('\n');
-
-        computer.provide_ascii_input(command);
-        result = computer.compute();
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
 
-        println!("{}", computer.read_ascii_output());
+fn main() {
+    let options =
+        parse_args(env::args().skip(1)).unwrap_or_else(|why| fail(format!("{}\n{}", why, USAGE)));
+
+    if options.format == Format::Json {
+        let input = load_or_exit(&options.path);
+        let mut report = Report::init(25, options.format);
+        let computer = report.parse(|| parse(&input));
+
+        report.part(1, || part1(&computer));
+        return;
     }
 
-    let items = vec![
-        "mutex",
-        "ornament",
-        "astrolabe",
-        "sand",
-        "semiconductor",
-        "dehydrated water",
-        "shell",
-        "klein bottle",
-    ];
-
-    let mut inv: HashSet<&str> = items.iter().cloned().collect();
-
-    let alerts = [
-        "Droids on this ship are lighter than the detected value!",
-        "Droids on this ship are heavier than the detected value!",
-    ];
-
-    for i in (0..2_i32.pow(8)).rev() {
-        for (k, item) in items.iter().enumerate().take(8_usize) {
-            let j = i / 2_i32.pow(k as u32) % 2;
-
-            if j == 1 && !inv.contains(item) {
-                let mut instruction = String::from("take ");
-                instruction.push_str(item);
-                instruction.push('\n');
-
-                computer.provide_ascii_input(instruction);
-                result = computer.compute();
-                println!("{}", computer.read_ascii_output());
-
-                inv.insert(item);
-            }
+    let show = |output: &str| println!("{}", output);
 
-            if j == 0 && inv.contains(item) {
-                let mut instruction = String::from("drop ");
-                instruction.push_str(item);
-                instruction.push('\n');
+    let (mut computer, mut result) = match &options.restore {
+        Some(path) => restore(path).unwrap_or_else(|why| fail(why)),
+        None if options.checkpoint => (
+            walk_to_checkpoint(&parse(&load_or_exit(&options.path)), show),
+            State::AwaitingInput,
+        ),
+        None => reach_checkpoint(&parse(&load_or_exit(&options.path)), show),
+    };
 
-                computer.provide_ascii_input(instruction);
-                result = computer.compute();
-                println!("{}", computer.read_ascii_output());
+    while result == State::AwaitingInput {
+        let mut instruction = String::new();
 
-                inv.remove(item);
-            }
+        let read = io::stdin()
+            .read_line(&mut instruction)
+            .expect("Failed to read line");
+
+        if read == 0 {
+            break;
         }
 
-        let instruction = String::from("south\n");
+        if let Some(path) = instruction.trim().strip_prefix("save ") {
+            match computer.save_snapshot(path) {
+                Ok(()) => println!("saved to {}\n", path),
+                Err(why) => println!("couldn't save {}: {}\n", path, why),
+            }
 
-        computer.provide_ascii_input(instruction);
-        result = computer.compute();
+            continue;
+        }
 
-        let output = computer.read_ascii_output();
-        println!("{}", output);
+        if let Some(path) = instruction.trim().strip_prefix("load ") {
+            match restore(path) {
+                Ok(restored) => {
+                    println!("restored from {}\n", path);
+                    computer = restored.0;
+                    result = restored.1;
+                }
+                Err(why) => println!("{}\n", why),
+            }
 
-        if !output.contains(alerts[0]) && !output.contains(alerts[1]) {
-            break;
+            continue;
         }
+
+        computer.provide_ascii_input(&instruction);
+        result = computer
+            .compute()
+            .unwrap_or_else(|why| fail(format!("the droid crashed: {}", why)));
+        println!("{}", computer.read_ascii_output());
     }
+}
 
-    while result == State::AwaitingInput {
-        let mut instruction = String::new();
+fn restore(path: &str) -> Result<(Computer, State), String> {
+    let mut computer = Computer::restore_snapshot(path)
+        .map_err(|why| format!("couldn't restore {}: {}", path, why))?;
+    let result = computer
+        .compute()
+        .map_err(|why| format!("the game in {} crashed: {}", path, why))?;
 
-        io::stdin()
-            .read_line(&mut instruction)
-            .expect("Failed to read line");
+    println!("{}", computer.read_ascii_output());
 
-        computer.provide_ascii_input(instruction);
-        result = computer.compute();
-        println!("{}", computer.read_ascii_output());
-    }
+    Ok((computer, result))
 }
@@ -0,0 +1,38 @@
+use intcode::Computer;
+
+const TARGET: isize = 19_690_720;
+
+pub fn parse(input: &str) -> Computer {
+    let mut computer =
+        Computer::init(input).unwrap_or_else(|why| panic!("couldn't load the program: {}", why));
+    computer.compile();
+
+    computer
+}
+
+fn run(computer: &mut Computer, noun: isize, verb: isize) -> Option<isize> {
+    computer.reset();
+    computer.write_memory(1, noun);
+    computer.write_memory(2, verb);
+
+    computer.run().ok().map(|()| computer.read_memory(0))
+}
+
+/// The program's output in the "1202 program alarm" state.
+pub fn part1(computer: &Computer) -> isize {
+    run(&mut computer.clone(), 12, 2).unwrap()
+}
+
+pub fn part2(computer: &Computer) -> isize {
+    let mut computer = computer.clone();
+
+    for parameter1 in 0..100 {
+        for parameter2 in 0..100 {
+            if run(&mut computer, parameter1, parameter2) == Some(TARGET) {
+                return 100 * parameter1 + parameter2;
+            };
+        }
+    }
+
+    panic!("no noun and verb produce {}!", TARGET)
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Steps along each wire to the first visit of every point it passes.
+pub type Input = [HashMap<(i32, i32), i32>; 2];
+
+pub fn parse(input: &str) -> Input {
+    let wires: Vec<&str> = input.lines().collect();
+    let mut wire_coordinates = [HashMap::new(), HashMap::new()];
+
+    for k in 0..=1 {
+        let mut current_coord = (0, 0);
+        let mut total_distance = 0;
+
+        for leg in wires[k].split(',') {
+            match &leg[0..1] {
+                "R" => {
+                    let distance: i32 = leg[1..leg.len()].parse().unwrap();
+                    for _i in 1..=distance {
+                        current_coord = (current_coord.0 + 1, current_coord.1);
+                        total_distance += 1;
+                        wire_coordinates[k]
+                            .entry(current_coord)
+                            .or_insert(total_distance);
+                    }
+                }
+                "U" => {
+                    let distance: i32 = leg[1..leg.len()].parse().unwrap();
+                    for _i in 1..=distance {
+                        current_coord = (current_coord.0, current_coord.1 + 1);
+                        total_distance += 1;
+                        wire_coordinates[k]
+                            .entry(current_coord)
+                            .or_insert(total_distance);
+                    }
+                }
+                "L" => {
+                    let distance: i32 = leg[1..leg.len()].parse().unwrap();
+                    for _i in 1..=distance {
+                        current_coord = (current_coord.0 - 1, current_coord.1);
+                        total_distance += 1;
+                        wire_coordinates[k]
+                            .entry(current_coord)
+                            .or_insert(total_distance);
+                    }
+                }
+                "D" => {
+                    let distance: i32 = leg[1..leg.len()].parse().unwrap();
+                    for _i in 1..=distance {
+                        current_coord = (current_coord.0, current_coord.1 - 1);
+                        total_distance += 1;
+                        wire_coordinates[k]
+                            .entry(current_coord)
+                            .or_insert(total_distance);
+                    }
+                }
+                _ => panic!("wrong direction!"),
+            }
+        }
+    }
+
+    wire_coordinates
+}
+
+fn intersections(wire_coordinates: &Input) -> HashSet<&(i32, i32)> {
+    wire_coordinates[0]
+        .keys()
+        .collect::<HashSet<&(i32, i32)>>()
+        .intersection(&wire_coordinates[1].keys().collect::<HashSet<&(i32, i32)>>())
+        .cloned()
+        .collect()
+}
+
+pub fn part1(wire_coordinates: &Input) -> i32 {
+    intersections(wire_coordinates)
+        .iter()
+        .map(|(x, y)| x.abs() + y.abs())
+        .min()
+        .unwrap()
+}
+
+pub fn part2(wire_coordinates: &Input) -> i32 {
+    intersections(wire_coordinates)
+        .iter()
+        .map(|i| wire_coordinates[0].get(i).unwrap() + wire_coordinates[1].get(i).unwrap())
+        .min()
+        .unwrap()
+}
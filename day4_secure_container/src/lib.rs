@@ -0,0 +1,58 @@
+/// The lowest and highest password in the range.
+pub type Input = (u32, u32);
+
+pub fn parse(input: &str) -> Input {
+    let range: Vec<u32> = input
+        .trim()
+        .split('-')
+        .map(|bound| bound.parse().unwrap())
+        .collect();
+
+    (range[0], range[1])
+}
+
+/// Counts the numbers in range whose digits never decrease and pass `valid`.
+fn count(&(low, high): &Input, valid: impl Fn([u32; 6]) -> bool) -> usize {
+    let mut count = 0;
+
+    for a in low / 100_000..=high / 100_000 {
+        for b in a..=9 {
+            for c in b..=9 {
+                for d in c..=9 {
+                    for e in d..=9 {
+                        for f in e..=9 {
+                            let number =
+                                100_000 * a + 10_000 * b + 1_000 * c + 100 * d + 10 * e + f;
+
+                            if number < low {
+                                continue;
+                            } else if number > high {
+                                break;
+                            } else if valid([a, b, c, d, e, f]) {
+                                count += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    count
+}
+
+pub fn part1(range: &Input) -> usize {
+    count(range, |digits| {
+        digits.windows(2).any(|pair| pair[0] == pair[1])
+    })
+}
+
+pub fn part2(range: &Input) -> usize {
+    count(range, |[a, b, c, d, e, f]| {
+        (a == b) && (a != c)
+            || (b == c) && (b != d) && (b != a)
+            || (c == d) && (c != b) && (c != e)
+            || (d == e) && (d != c) && (d != f)
+            || (e == f) && (d != e)
+    })
+}
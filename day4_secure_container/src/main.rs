@@ -1,33 +1,12 @@
-fn main() {
-    let mut count = 0;
-
-    for a in 2..=7 {
-        for b in a..=9 {
-            for c in b..=9 {
-                for d in c..=9 {
-                    for e in d..=9 {
-                        for f in e..=9 {
-                            let number =
-                                100_000 * a + 10_000 * b + 1_000 * c + 100 * d + 10 * e + f;
+use day4_secure_container::{parse, part1, part2};
+use input::read_input;
+use report::report;
 
-                            if number < 245_182 {
-                                continue;
-                            } else if number > 790_572 {
-                                break;
-                            } else if (a == b) && (a != c)
-                                || (b == c) && (b != d) && (b != a)
-                                || (c == d) && (c != b) && (c != e)
-                                || (d == e) && (d != c) && (d != f)
-                                || (e == f) && (d != e)
-                            {
-                                count += 1;
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
+fn main() {
+    let input = read_input();
+    let mut report = report(4);
+    let range = report.parse(|| parse(&input));
 
-    println!("{}", count);
+    report.part(1, || part1(&range));
+    report.part(2, || part2(&range));
 }
@@ -0,0 +1,25 @@
+use intcode::Computer;
+
+const AIR_CONDITIONER: isize = 1;
+const THERMAL_RADIATOR_CONTROLLER: isize = 5;
+
+pub fn parse(input: &str) -> Computer {
+    Computer::init(input).unwrap_or_else(|why| panic!("couldn't load the program: {}", why))
+}
+
+/// The diagnostic code, which the program outputs last.
+fn diagnose(computer: &Computer, system: isize) -> isize {
+    let mut computer = computer.clone();
+    computer.provide_input(system);
+    computer.run().unwrap();
+
+    *computer.read_outputs().last().unwrap()
+}
+
+pub fn part1(computer: &Computer) -> isize {
+    diagnose(computer, AIR_CONDITIONER)
+}
+
+pub fn part2(computer: &Computer) -> isize {
+    diagnose(computer, THERMAL_RADIATOR_CONTROLLER)
+}
@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+fn path_to_sun<'a>(start: &'a str, orbits: &'a HashMap<&'a str, &'a str>) -> HashSet<&'a str> {
+    let mut k = start;
+    let mut path = HashSet::new();
+
+    while orbits.contains_key(k) {
+        k = orbits.get(k).unwrap();
+        path.insert(k);
+    }
+
+    path
+}
+
+/// What every object orbits directly.
+pub type Input<'a> = HashMap<&'a str, &'a str>;
+
+pub fn parse(input: &str) -> Input<'_> {
+    input
+        .lines()
+        .map(|x| {
+            let pair: Vec<&str> = x.split(')').collect();
+            (pair[1], pair[0])
+        })
+        .collect()
+}
+
+/// Direct and indirect orbits.
+pub fn part1(orbits: &Input) -> usize {
+    orbits.keys().map(|k| path_to_sun(k, orbits).len()).sum()
+}
+
+/// Orbital transfers between the objects YOU and SAN orbit.
+pub fn part2(orbits: &Input) -> usize {
+    let path_from_me_to_sun: HashSet<&str> = path_to_sun("YOU", orbits);
+    let path_from_santa_to_sun: HashSet<&str> = path_to_sun("SAN", orbits);
+
+    path_from_me_to_sun
+        .symmetric_difference(&path_from_santa_to_sun)
+        .count()
+}
@@ -0,0 +1,94 @@
+use intcode::{Computer, State};
+use std::collections::HashSet;
+
+fn generate_phases_combinations(
+    k: usize,
+    permutation: &mut [usize; 5],
+    phases_combinations: &mut HashSet<[usize; 5]>,
+) {
+    if k == 1 {
+        phases_combinations.insert(*permutation);
+    } else {
+        generate_phases_combinations(k - 1, permutation, phases_combinations);
+
+        for i in 0..k - 1 {
+            if k.is_multiple_of(2) {
+                permutation[i] += permutation[k - 1];
+                permutation[k - 1] = permutation[i] - permutation[k - 1];
+                permutation[i] -= permutation[k - 1];
+            } else {
+                permutation[0] += permutation[k - 1];
+                permutation[k - 1] = permutation[0] - permutation[k - 1];
+                permutation[0] -= permutation[k - 1];
+            }
+
+            generate_phases_combinations(k - 1, permutation, phases_combinations);
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Computer {
+    Computer::init(input).unwrap_or_else(|why| panic!("couldn't load the program: {}", why))
+}
+
+/// The highest signal amplifiers in series can send to the thrusters.
+pub fn part1(computer: &Computer) -> isize {
+    let mut phases_combinations: HashSet<[usize; 5]> = HashSet::new();
+
+    generate_phases_combinations(5, &mut [0, 1, 2, 3, 4], &mut phases_combinations);
+
+    phases_combinations
+        .iter()
+        .map(|phases| {
+            phases.iter().fold(0, |signal, &phase| {
+                let mut amplifier = computer.clone();
+                amplifier.provide_input(phase as isize);
+                amplifier.provide_input(signal);
+                amplifier.run().unwrap();
+                amplifier.read_output().unwrap()
+            })
+        })
+        .max()
+        .unwrap()
+}
+
+/// The highest signal amplifiers in a feedback loop can send.
+pub fn part2(computer: &Computer) -> isize {
+    let mut phases_combinations: HashSet<[usize; 5]> = HashSet::new();
+    let mut results: HashSet<isize> = HashSet::new();
+
+    generate_phases_combinations(5, &mut [5, 6, 7, 8, 9], &mut phases_combinations);
+    //phases_combinations.insert([9, 8, 7, 6, 5]);
+
+    for phases in phases_combinations {
+        let mut computers: Vec<Computer> = phases
+            .iter()
+            .map(|&phase| {
+                let mut amplifier = computer.clone();
+                amplifier.provide_input(phase as isize);
+                amplifier
+            })
+            .collect();
+
+        let mut signal = 0;
+        let mut k = 0;
+
+        loop {
+            computers[k].provide_input(signal);
+
+            match computers[k].compute_until_output().unwrap() {
+                State::ProducedOutput => signal = computers[k].read_output().unwrap(),
+                State::Finished => break,
+                State::AwaitingInput | State::BudgetExhausted(_) => {
+                    panic!("amplifier {} is stuck!", k)
+                }
+            }
+
+            k = (k + 1) % phases.len();
+        }
+
+        results.insert(signal);
+    }
+
+    *results.iter().max().unwrap()
+}
@@ -0,0 +1,52 @@
+const WIDTH: usize = 25;
+const HEIGHT: usize = 6;
+
+const SIZE: usize = WIDTH * HEIGHT;
+
+/// The pixels of every layer, one after another.
+pub type Input = Vec<char>;
+
+pub fn parse(input: &str) -> Input {
+    input.trim().chars().collect()
+}
+
+/// The number of 1 digits times the number of 2 digits in the layer with the
+/// fewest 0 digits.
+pub fn part1(pixels: &Input) -> usize {
+    let count = |layer: &[char], digit: char| layer.iter().filter(|&&c| c == digit).count();
+    let layer = pixels
+        .chunks(SIZE)
+        .min_by_key(|layer| count(layer, '0'))
+        .unwrap();
+
+    count(layer, '1') * count(layer, '2')
+}
+
+/// The decoded image, `true` for white pixels.
+pub fn image(pixels: &Input) -> Vec<Vec<bool>> {
+    let mut result: String = String::new();
+
+    for i in 0..SIZE {
+        for layer in pixels.chunks(SIZE) {
+            if layer[i] != '2' {
+                result.push(layer[i]);
+                break;
+            }
+        }
+    }
+
+    result
+        .chars()
+        .collect::<Vec<char>>()
+        .chunks(WIDTH)
+        .map(|row| row.iter().map(|&c| c == '1').collect())
+        .collect()
+}
+
+/// The message in the decoded image.
+pub fn part2(pixels: &Input) -> String {
+    let image = image(pixels);
+
+    ocr::recognize(&image)
+        .unwrap_or_else(|why| panic!("couldn't read the image: {}\n{}", why, ocr::render(&image)))
+}
@@ -0,0 +1,26 @@
+use intcode::Computer;
+
+const TEST_MODE: isize = 1;
+const SENSOR_BOOST_MODE: isize = 2;
+
+pub fn parse(input: &str) -> Computer {
+    Computer::init(input).unwrap_or_else(|why| panic!("couldn't load the program: {}", why))
+}
+
+fn boost(computer: &Computer, mode: isize) -> isize {
+    let mut computer = computer.clone();
+    computer.provide_input(mode);
+    computer.run().unwrap();
+
+    *computer.read_outputs().last().unwrap()
+}
+
+/// The BOOST keycode.
+pub fn part1(computer: &Computer) -> isize {
+    boost(computer, TEST_MODE)
+}
+
+/// The coordinates of the distress signal.
+pub fn part2(computer: &Computer) -> isize {
+    boost(computer, SENSOR_BOOST_MODE)
+}
@@ -0,0 +1,151 @@
+use memmap2::Mmap;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::ops::Deref;
+use std::process;
+use std::str;
+
+pub const DEFAULT_PATH: &str = "input.txt";
+
+/// The path that means standard input.
+pub const STDIN: &str = "-";
+
+/// Options every day binary takes, each followed by a value.
+const OPTIONS: [&str; 1] = ["--format"];
+
+#[derive(Debug)]
+pub enum InputError {
+    Open { path: String, error: io::Error },
+    Read { path: String, error: io::Error },
+    NotUtf8 { path: String },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Open { path, error } => write!(f, "couldn't open {}: {}", path, error),
+            InputError::Read { path, error } => write!(f, "couldn't read {}: {}", path, error),
+            InputError::NotUtf8 { path } => write!(f, "{} is not valid UTF-8", path),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+enum Contents {
+    Mapped(Mmap),
+    Read(String),
+}
+
+/// A puzzle input. Files are memory-mapped rather than copied; anything that
+/// can't be mapped, like a pipe, is read into memory instead.
+pub struct Input {
+    contents: Contents,
+}
+
+impl Input {
+    /// Loads `path`, or standard input if it is `-`.
+    pub fn load(path: &str) -> Result<Input, InputError> {
+        let display = || {
+            if path == STDIN {
+                String::from("standard input")
+            } else {
+                String::from(path)
+            }
+        };
+
+        let read = |reader: &mut dyn Read| {
+            let mut s = String::new();
+
+            match reader.read_to_string(&mut s) {
+                Ok(_) => Ok(Input {
+                    contents: Contents::Read(s),
+                }),
+                Err(ref error) if error.kind() == io::ErrorKind::InvalidData => {
+                    Err(InputError::NotUtf8 { path: display() })
+                }
+                Err(error) => Err(InputError::Read {
+                    path: display(),
+                    error,
+                }),
+            }
+        };
+
+        if path == STDIN {
+            return read(&mut io::stdin());
+        }
+
+        let mut file = File::open(path).map_err(|error| InputError::Open {
+            path: display(),
+            error,
+        })?;
+
+        // Safety: the puzzle input isn't expected to change while it is read.
+        match unsafe { Mmap::map(&file) } {
+            Ok(map) if str::from_utf8(&map).is_ok() => Ok(Input {
+                contents: Contents::Mapped(map),
+            }),
+            Ok(_) => Err(InputError::NotUtf8 { path: display() }),
+            Err(_) => read(&mut file),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match &self.contents {
+            // Safety: `load` only maps files that are valid UTF-8.
+            Contents::Mapped(map) => unsafe { str::from_utf8_unchecked(map) },
+            Contents::Read(s) => s,
+        }
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// The input path in the command line arguments `args`, `input.txt` if
+/// there is none. The values of known options are skipped; any other option
+/// is an error.
+pub fn input_path(mut args: impl Iterator<Item = String>) -> Result<String, String> {
+    let mut path = None;
+
+    while let Some(arg) = args.next() {
+        if OPTIONS.contains(&arg.as_str()) {
+            args.next().ok_or(format!("{} needs a value", arg))?;
+        } else if arg.starts_with("--") {
+            return Err(format!("there is no {} option", arg));
+        } else if path.replace(arg).is_some() {
+            return Err(String::from("there can only be one input"));
+        }
+    }
+
+    Ok(path.unwrap_or_else(|| String::from(DEFAULT_PATH)))
+}
+
+/// Loads `path`, or prints why it couldn't and exits.
+pub fn load_or_exit(path: &str) -> Input {
+    Input::load(path).unwrap_or_else(|why| {
+        eprintln!("{}", why);
+        process::exit(1);
+    })
+}
+
+/// Loads the input named on the command line, or prints what is wrong with
+/// the arguments and exits.
+pub fn read_input() -> Input {
+    let path = input_path(env::args().skip(1)).unwrap_or_else(|why| {
+        eprintln!("{}", why);
+        process::exit(1);
+    });
+
+    load_or_exit(&path)
+}
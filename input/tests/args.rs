@@ -0,0 +1,40 @@
+use input::input_path;
+
+fn path(args: &[&str]) -> Result<String, String> {
+    input_path(args.iter().map(|arg| arg.to_string()))
+}
+
+#[test]
+fn path_defaults_to_input_txt() {
+    assert_eq!(path(&[]), Ok(String::from("input.txt")));
+    assert_eq!(path(&["--format", "json"]), Ok(String::from("input.txt")));
+}
+
+#[test]
+fn option_values_are_not_paths() {
+    assert_eq!(
+        path(&["--format", "json", "x.txt"]),
+        Ok(String::from("x.txt"))
+    );
+    assert_eq!(
+        path(&["x.txt", "--format", "text"]),
+        Ok(String::from("x.txt"))
+    );
+    assert_eq!(path(&["-", "--format", "text"]), Ok(String::from("-")));
+}
+
+#[test]
+fn unknown_options_and_extra_paths_are_rejected() {
+    assert_eq!(
+        path(&["--frames", "10", "x.txt"]),
+        Err(String::from("there is no --frames option"))
+    );
+    assert_eq!(
+        path(&["x.txt", "--format"]),
+        Err(String::from("--format needs a value"))
+    );
+    assert_eq!(
+        path(&["x.txt", "y.txt"]),
+        Err(String::from("there can only be one input"))
+    );
+}
@@ -0,0 +1,93 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use intcode::Computer;
+
+const PROGRAM_ALARM: &str = include_str!("../../day2_program_alarm/input.txt");
+const TRACTOR_BEAM: &str = include_str!("../../day19_tractor_beam/input.txt");
+const CRYOSTASIS: &str = include_str!("../../day25_cryostasis/input.txt");
+
+const WALK: [&str; 8] = [
+    "east",
+    "take klein bottle",
+    "east",
+    "take semiconductor",
+    "west",
+    "north",
+    "north",
+    "north",
+];
+
+fn computer(program: &str, compiled: bool) -> Computer {
+    let mut computer = Computer::init(program).unwrap();
+
+    if compiled {
+        computer.compile();
+    }
+
+    computer
+}
+
+fn noun_and_verb(computer: &mut Computer) -> Option<isize> {
+    for noun in 0..100 {
+        for verb in 0..100 {
+            computer.reset();
+            computer.write_memory(1, noun);
+            computer.write_memory(2, verb);
+
+            if computer.run().is_ok() && computer.read_memory(0) == 19_690_720 {
+                return Some(100 * noun + verb);
+            }
+        }
+    }
+
+    None
+}
+
+fn affected_points(computer: &mut Computer) -> isize {
+    let mut count = 0;
+
+    for y in 0..50 {
+        for x in 0..50 {
+            computer.reset();
+            computer.provide_input(x);
+            computer.provide_input(y);
+            computer.run().unwrap();
+            count += computer.read_output().unwrap();
+        }
+    }
+
+    count
+}
+
+fn walk(computer: &mut Computer) -> String {
+    computer.reset();
+    computer.compute().unwrap();
+
+    for command in WALK.iter() {
+        computer.provide_ascii_input(&format!("{}\n", command));
+        computer.compute().unwrap();
+    }
+
+    computer.read_ascii_output()
+}
+
+fn compare<T>(c: &mut Criterion, name: &str, program: &str, solve: fn(&mut Computer) -> T) {
+    let mut group = c.benchmark_group(name);
+    group.sample_size(10);
+
+    let mut interpreted = computer(program, false);
+    group.bench_function("interpreted", |b| b.iter(|| solve(&mut interpreted)));
+
+    let mut compiled = computer(program, true);
+    group.bench_function("compiled", |b| b.iter(|| solve(&mut compiled)));
+
+    group.finish();
+}
+
+fn compiler_benchmark(c: &mut Criterion) {
+    compare(c, "day2", PROGRAM_ALARM, noun_and_verb);
+    compare(c, "day19", TRACTOR_BEAM, affected_points);
+    compare(c, "day25", CRYOSTASIS, walk);
+}
+
+criterion_group!(benches, compiler_benchmark);
+criterion_main!(benches);
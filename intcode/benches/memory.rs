@@ -0,0 +1,26 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use intcode::{BigInt, Computer, FlatMemory, Memory, SparseMemory, Word};
+
+const BOOST: &str = include_str!("../../day9_sensor_boost/input.txt");
+
+fn run_boost<M: Memory>() -> Option<M::Word> {
+    let mut computer: Computer<M> = Computer::load(BOOST).unwrap();
+    computer.provide_input(M::Word::from_isize(2));
+    computer.run().unwrap();
+    computer.read_output()
+}
+
+fn memory_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("boost");
+    group.sample_size(10);
+
+    group.bench_function("flat", |b| b.iter(run_boost::<FlatMemory>));
+    group.bench_function("sparse", |b| b.iter(run_boost::<SparseMemory>));
+    group.bench_function("flat_i128", |b| b.iter(run_boost::<FlatMemory<i128>>));
+    group.bench_function("flat_bigint", |b| b.iter(run_boost::<FlatMemory<BigInt>>));
+
+    group.finish();
+}
+
+criterion_group!(benches, memory_benchmark);
+criterion_main!(benches);
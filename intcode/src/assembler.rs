@@ -0,0 +1,264 @@
+use crate::disassembler::{Entry, Line};
+use crate::error::AssemblyError;
+use crate::instruction::{Instruction, Mode, Opcode, Parameter};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug)]
+enum Value {
+    Number(isize),
+    Label(String, isize),
+}
+
+#[derive(Clone, Debug)]
+enum Item {
+    Code(Opcode, Vec<(Mode, Value)>),
+    Data(Vec<Value>),
+}
+
+struct Statement {
+    line: usize,
+    address: usize,
+    item: Item,
+}
+
+fn is_label(text: &str) -> bool {
+    let mut chars = text.chars();
+
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+        }
+        _ => false,
+    }
+}
+
+fn parse_value(text: &str) -> Option<Value> {
+    let text = text.trim();
+
+    if let Ok(number) = text.parse::<isize>() {
+        return Some(Value::Number(number));
+    }
+
+    if is_label(text) {
+        return Some(Value::Label(text.to_string(), 0));
+    }
+
+    let split = text.rfind(['+', '-'])?;
+    let (label, offset) = (text[..split].trim(), text[split..].replace(' ', ""));
+    let offset = offset.trim_start_matches('+').parse::<isize>().ok()?;
+
+    if is_label(label) {
+        Some(Value::Label(label.to_string(), offset))
+    } else {
+        None
+    }
+}
+
+fn parse_operand(text: &str) -> Option<(Mode, Value)> {
+    let text = text.trim();
+
+    if let Some(value) = text.strip_prefix('#') {
+        Some((Mode::Immediate, parse_value(value)?))
+    } else if let Some(value) = text.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        Some((Mode::Position, parse_value(value)?))
+    } else if let Some(offset) = text.strip_prefix("rel") {
+        let offset = offset.trim();
+
+        if offset.is_empty() {
+            Some((Mode::Relative, Value::Number(0)))
+        } else if let Some(value) = offset.strip_prefix('+') {
+            Some((Mode::Relative, parse_value(value)?))
+        } else if offset.starts_with('-') {
+            Some((Mode::Relative, Value::Number(offset.parse().ok()?)))
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+fn split_operands(text: &str) -> Vec<&str> {
+    if text.trim().is_empty() {
+        Vec::new()
+    } else {
+        text.split(',').map(|operand| operand.trim()).collect()
+    }
+}
+
+fn parse_item(line: usize, text: &str) -> Result<Item, AssemblyError> {
+    let (mnemonic, rest) = match text.find(char::is_whitespace) {
+        Some(split) => (&text[..split], &text[split..]),
+        None => (text, ""),
+    };
+    let syntax_error = || AssemblyError::Syntax {
+        line,
+        text: text.to_string(),
+    };
+
+    if mnemonic == ".data" {
+        let values = split_operands(rest)
+            .into_iter()
+            .map(parse_value)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(syntax_error)?;
+
+        return Ok(Item::Data(values));
+    }
+
+    let opcode = Opcode::from_mnemonic(mnemonic).ok_or_else(|| AssemblyError::UnknownMnemonic {
+        line,
+        mnemonic: mnemonic.to_string(),
+    })?;
+
+    let operands = split_operands(rest)
+        .into_iter()
+        .map(parse_operand)
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(syntax_error)?;
+
+    if operands.len() != opcode.number_of_params() {
+        return Err(AssemblyError::WrongOperandCount {
+            line,
+            mnemonic: mnemonic.to_string(),
+            expected: opcode.number_of_params(),
+            found: operands.len(),
+        });
+    }
+
+    if opcode.writes_memory() && operands.last().map(|(mode, _)| *mode) == Some(Mode::Immediate) {
+        return Err(AssemblyError::ImmediateOutput { line });
+    }
+
+    Ok(Item::Code(opcode, operands))
+}
+
+/// Splits off the labels and listing addresses in front of the statement.
+fn strip_prefixes<'a>(
+    line: usize,
+    mut text: &'a str,
+    address: usize,
+    labels: &mut HashMap<String, usize>,
+) -> Result<&'a str, AssemblyError> {
+    while let Some(colon) = text.find(':') {
+        let prefix = text[..colon].trim();
+
+        if is_label(prefix) {
+            if labels.insert(prefix.to_string(), address).is_some() {
+                return Err(AssemblyError::DuplicateLabel {
+                    line,
+                    label: prefix.to_string(),
+                });
+            }
+        } else if prefix.is_empty() || !prefix.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AssemblyError::Syntax {
+                line,
+                text: text.to_string(),
+            });
+        }
+
+        text = text[colon + 1..].trim();
+    }
+
+    Ok(text)
+}
+
+fn resolve(
+    line: usize,
+    value: &Value,
+    labels: &HashMap<String, usize>,
+) -> Result<isize, AssemblyError> {
+    match value {
+        Value::Number(number) => Ok(*number),
+        Value::Label(label, offset) => labels
+            .get(label)
+            .map(|&address| address as isize + offset)
+            .ok_or_else(|| AssemblyError::UnknownLabel {
+                line,
+                label: label.clone(),
+            }),
+    }
+}
+
+/// Assembles the source into listing lines, one per statement.
+///
+/// Accepts the output of `listing`, so the leading addresses are skipped.
+pub fn assemble_lines(source: &str) -> Result<Vec<Line>, AssemblyError> {
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+    let mut address = 0;
+
+    for (i, text) in source.lines().enumerate() {
+        let line = i + 1;
+        let text = text.split(';').next().unwrap().trim();
+        let text = strip_prefixes(line, text, address, &mut labels)?;
+
+        if text.is_empty() {
+            continue;
+        }
+
+        let item = parse_item(line, text)?;
+
+        let length = match &item {
+            Item::Code(opcode, _) => opcode.number_of_params() + 1,
+            Item::Data(values) => values.len(),
+        };
+
+        statements.push(Statement {
+            line,
+            address,
+            item,
+        });
+
+        address += length;
+    }
+
+    let mut lines = Vec::new();
+
+    for statement in statements {
+        let line = statement.line;
+
+        let entry = match statement.item {
+            Item::Code(opcode, operands) => {
+                let mut params = Vec::new();
+
+                for (mode, value) in operands.iter() {
+                    params.push(Parameter {
+                        mode: *mode,
+                        value: resolve(line, value, &labels)?,
+                    });
+                }
+
+                Entry::Code(Instruction { opcode, params })
+            }
+            Item::Data(values) => Entry::Data(
+                values
+                    .iter()
+                    .map(|value| resolve(line, value, &labels))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        };
+
+        lines.push(Line {
+            address: statement.address,
+            entry,
+        });
+    }
+
+    Ok(lines)
+}
+
+pub fn assemble(source: &str) -> Result<Vec<isize>, AssemblyError> {
+    Ok(assemble_lines(source)?
+        .iter()
+        .flat_map(|line| line.words())
+        .collect())
+}
+
+/// Assembles the source and prints it back in the same format as `listing`.
+pub fn assemble_listing(source: &str) -> Result<String, AssemblyError> {
+    Ok(assemble_lines(source)?
+        .iter()
+        .map(|line| format!("{}\n", line))
+        .collect())
+}
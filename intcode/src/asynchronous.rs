@@ -0,0 +1,39 @@
+use crate::computer::{Computer, State};
+use crate::error::IntcodeError;
+use crate::memory::Memory;
+use futures::{pin_mut, Sink, SinkExt, Stream, StreamExt};
+
+impl<M: Memory> Computer<M> {
+    /// Like `compute_with_io`, but waits for input from an async stream and
+    /// sends output to an async sink, so computers can run as tasks.
+    ///
+    /// Returns `AwaitingInput` once the stream ends. Output the sink refuses
+    /// is dropped.
+    pub async fn compute_async<I, O>(&mut self, input: I, output: O) -> Result<State, IntcodeError>
+    where
+        I: Stream<Item = M::Word>,
+        O: Sink<M::Word>,
+    {
+        pin_mut!(input);
+        pin_mut!(output);
+
+        for value in self.read_outputs() {
+            let _ = output.send(value).await;
+        }
+
+        loop {
+            match self.step()? {
+                Some(State::AwaitingInput) => match input.next().await {
+                    Some(value) => self.provide_input(value),
+                    None => return Ok(State::AwaitingInput),
+                },
+                Some(State::ProducedOutput) => {
+                    let value = self.read_output().unwrap();
+                    let _ = output.send(value).await;
+                }
+                Some(state) => return Ok(state),
+                None => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use intcode::{assemble, assemble_listing, format_program};
+use std::env;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+use std::process;
+
+fn read_source(path: Option<String>) -> io::Result<String> {
+    let mut s = String::new();
+
+    match path {
+        Some(path) => File::open(path)?.read_to_string(&mut s)?,
+        None => io::stdin().read_to_string(&mut s)?,
+    };
+
+    Ok(s)
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let listing = args.iter().any(|arg| arg == "--listing");
+    args.retain(|arg| arg != "--listing");
+
+    let source = match read_source(args.into_iter().next()) {
+        Ok(source) => source,
+        Err(why) => {
+            eprintln!("couldn't read source: {}", why);
+            process::exit(1);
+        }
+    };
+
+    let result = if listing {
+        assemble_listing(&source)
+    } else {
+        assemble(&source).map(|program| format!("{}\n", format_program(&program)))
+    };
+
+    match result {
+        Ok(output) => print!("{}", output),
+        Err(why) => {
+            eprintln!("{}", why);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use intcode::{parse_program, ControlFlowGraph};
+use std::env;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+use std::process;
+
+const USAGE: &str = "usage: cfg [program] [--dot]";
+
+fn read_program(path: Option<String>) -> io::Result<String> {
+    let mut s = String::new();
+
+    match path {
+        Some(path) => File::open(path)?.read_to_string(&mut s)?,
+        None => io::stdin().read_to_string(&mut s)?,
+    };
+
+    Ok(s)
+}
+
+fn main() {
+    let mut path = None;
+    let mut dot = false;
+
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--dot" => dot = true,
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(arg),
+            _ => {
+                eprintln!("unexpected argument {}\n{}", arg, USAGE);
+                process::exit(1);
+            }
+        }
+    }
+
+    let program = match read_program(path) {
+        Ok(program) => program,
+        Err(why) => {
+            eprintln!("couldn't read program: {}", why);
+            process::exit(1);
+        }
+    };
+
+    let graph = match parse_program(&program) {
+        Ok(program) => ControlFlowGraph::build(&program),
+        Err(why) => {
+            eprintln!("couldn't parse program: {}", why);
+            process::exit(1);
+        }
+    };
+
+    if dot {
+        print!("{}", graph.to_dot());
+    } else {
+        print!("{}", graph);
+
+        let functions: Vec<String> = graph
+            .functions()
+            .iter()
+            .map(|address| format!("{:04}", address))
+            .collect();
+        let indirect: Vec<String> = graph
+            .indirect_jumps()
+            .iter()
+            .map(|address| format!("{:04}", address))
+            .collect();
+
+        println!("\nfunctions: {}", functions.join(", "));
+        println!("indirect jumps: {}", indirect.join(", "));
+    }
+}
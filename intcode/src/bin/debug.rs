@@ -0,0 +1,302 @@
+use intcode::{Computer, Debugger, Instruction, Opcode, Stop};
+use std::env;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+use std::process;
+
+const JOURNAL_CAPACITY: usize = 100_000;
+
+const HELP: &str = "\
+step [n]              execute n instructions (s)
+back [n|io]           undo n instructions, or back to before the last input or output
+journal <n>           keep the last n instructions for undoing, 100000 by default
+continue              run to the next breakpoint, watchpoint, halt or input request (c)
+break <addr|mnemonic> stop before an address or any instruction with that opcode (b)
+delete <addr|mnemonic> remove a breakpoint (d)
+watch <addr>          stop when the memory cell changes (w)
+unwatch <addr>        remove a watchpoint
+info                  show registers, queues, breakpoints and watchpoints (i)
+examine <addr> [n]    print n memory cells (x)
+list [addr] [n]       disassemble n instructions, from the pointer by default (l)
+poke <addr> <value>   write to memory
+input <values>        queue comma or space separated integers as input
+ascii <text>          queue the text and a newline as ASCII input
+output                print and clear the pending output (o)
+reset                 restart the program
+quit                  exit (q)
+An empty line repeats the previous command.";
+
+fn read_program(path: &str) -> io::Result<String> {
+    let mut s = String::new();
+    File::open(path)?.read_to_string(&mut s)?;
+
+    Ok(s)
+}
+
+fn parse_number<T: std::str::FromStr>(arg: Option<&str>) -> Result<T, String> {
+    let arg = arg.ok_or("missing argument")?;
+    arg.parse::<T>()
+        .map_err(|_| format!("{} is not a number", arg))
+}
+
+fn describe(stop: Stop) -> String {
+    match stop {
+        Stop::Stepped => String::new(),
+        Stop::Output(value) => format!("output {}", value),
+        Stop::Breakpoint(address) => format!("breakpoint at {}", address),
+        Stop::OpcodeBreakpoint(opcode) => format!("breakpoint on {}", opcode.mnemonic()),
+        Stop::Watchpoint { address, old, new } => {
+            format!("[{}] changed from {} to {}", address, old, new)
+        }
+        Stop::AwaitingInput => "waiting for input".to_string(),
+        Stop::BudgetExhausted(limit) => format!("{} budget exhausted", limit),
+        Stop::Finished => "halted".to_string(),
+    }
+}
+
+fn list(debugger: &Debugger, mut address: usize, count: usize) {
+    let computer = debugger.computer();
+    let pointer = computer.pointer() as usize;
+
+    for _ in 0..count {
+        let words: Vec<isize> = (address..address + 4)
+            .map(|a| computer.read_memory(a))
+            .collect();
+        let marker = if address == pointer { "=>" } else { "  " };
+
+        match Instruction::decode(&words) {
+            Some(instruction) => {
+                println!("{} {:04}: {}", marker, address, instruction);
+                address += instruction.word_count();
+            }
+            None => {
+                println!("{} {:04}: .data {}", marker, address, words[0]);
+                address += 1;
+            }
+        }
+    }
+}
+
+fn join<T: ToString>(values: impl Iterator<Item = T>) -> String {
+    values
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn info(debugger: &Debugger) {
+    let computer = debugger.computer();
+    let opcodes = debugger.opcode_breakpoints();
+
+    println!("pointer:       {}", computer.pointer());
+    println!("relative base: {}", computer.relative_base());
+    println!("input:         [{}]", join(computer.pending_input().iter()));
+    println!(
+        "output:        [{}]",
+        join(computer.pending_output().iter())
+    );
+    println!(
+        "breakpoints:   {}",
+        join(
+            debugger
+                .breakpoints()
+                .iter()
+                .map(|address| address.to_string())
+                .chain(opcodes.iter().map(|opcode| opcode.mnemonic().to_string()))
+        )
+    );
+    println!("watchpoints:   {}", join(debugger.watchpoints().iter()));
+}
+
+fn print_output(debugger: &mut Debugger) {
+    let outputs = debugger.computer_mut().read_outputs();
+
+    if !outputs.is_empty() && outputs.iter().all(|&c| c > 0 && c < 128) {
+        print!(
+            "{}",
+            outputs.iter().map(|&c| c as u8 as char).collect::<String>()
+        );
+    } else {
+        for output in outputs {
+            println!("{}", output);
+        }
+    }
+}
+
+fn run_command(debugger: &mut Debugger, command: &str) -> Result<bool, String> {
+    let (name, rest) = match command.find(char::is_whitespace) {
+        Some(split) => (&command[..split], command[split..].trim()),
+        None => (command, ""),
+    };
+    let mut args = rest.split_whitespace();
+
+    match name {
+        "s" | "step" => {
+            let count = if rest.is_empty() {
+                1
+            } else {
+                parse_number::<usize>(args.next())?
+            };
+
+            for _ in 0..count {
+                match debugger.step().map_err(|e| e.to_string())? {
+                    Stop::Stepped | Stop::Output(_) => {}
+                    stop => {
+                        println!("{}", describe(stop));
+                        break;
+                    }
+                }
+            }
+
+            list(debugger, debugger.computer().pointer() as usize, 1);
+        }
+        "back" => {
+            let undone = match args.next() {
+                Some("io") => debugger.rewind_to_io(),
+                Some(arg) => debugger.step_back(parse_number(Some(arg))?),
+                None => debugger.step_back(1),
+            };
+
+            println!("undid {} instructions", undone);
+            list(debugger, debugger.computer().pointer() as usize, 1);
+        }
+        "journal" => debugger
+            .computer_mut()
+            .enable_journal(parse_number(args.next())?),
+        "c" | "continue" => {
+            let stop = debugger.resume().map_err(|e| e.to_string())?;
+            println!("{}", describe(stop));
+            list(debugger, debugger.computer().pointer() as usize, 1);
+        }
+        "b" | "break" | "d" | "delete" => {
+            let arg = args.next().ok_or("missing argument")?;
+            let add = name.starts_with('b');
+
+            if let Some(opcode) = Opcode::from_mnemonic(arg) {
+                if add {
+                    debugger.add_opcode_breakpoint(opcode);
+                } else if !debugger.remove_opcode_breakpoint(opcode) {
+                    return Err(format!("no breakpoint on {}", arg));
+                }
+            } else {
+                let address = parse_number::<usize>(Some(arg))?;
+
+                if add {
+                    debugger.add_breakpoint(address);
+                } else if !debugger.remove_breakpoint(address) {
+                    return Err(format!("no breakpoint at {}", arg));
+                }
+            }
+        }
+        "w" | "watch" => debugger.add_watchpoint(parse_number(args.next())?),
+        "unwatch" => {
+            if !debugger.remove_watchpoint(parse_number(args.next())?) {
+                return Err("no such watchpoint".to_string());
+            }
+        }
+        "i" | "info" => info(debugger),
+        "x" | "examine" => {
+            let address = parse_number::<usize>(args.next())?;
+            let count = args.next().map_or(Ok(1), |n| parse_number(Some(n)))?;
+
+            for a in address..address + count {
+                println!("{:04}: {}", a, debugger.computer().read_memory(a));
+            }
+        }
+        "l" | "list" => {
+            let address = match args.next() {
+                Some(arg) => parse_number::<usize>(Some(arg))?,
+                None => debugger.computer().pointer() as usize,
+            };
+            let count = args.next().map_or(Ok(10), |n| parse_number(Some(n)))?;
+
+            list(debugger, address, count);
+        }
+        "poke" => {
+            let address = parse_number::<usize>(args.next())?;
+            let value = parse_number::<isize>(args.next())?;
+
+            debugger.poke(address, value);
+        }
+        "input" => {
+            for value in rest.split(|c: char| c == ',' || c.is_whitespace()) {
+                if !value.is_empty() {
+                    let value = parse_number::<isize>(Some(value))?;
+                    debugger.computer_mut().provide_input(value);
+                }
+            }
+        }
+        "ascii" => debugger
+            .computer_mut()
+            .provide_ascii_input(&format!("{}\n", rest)),
+        "o" | "output" => print_output(debugger),
+        "reset" => debugger.reset(),
+        "q" | "quit" => return Ok(false),
+        "h" | "help" => println!("{}", HELP),
+        _ => return Err(format!("unknown command {:?}, try help", name)),
+    }
+
+    Ok(true)
+}
+
+fn main() {
+    let path = match env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: debug <program>");
+            process::exit(1);
+        }
+    };
+
+    let program = match read_program(&path) {
+        Ok(program) => program,
+        Err(why) => {
+            eprintln!("couldn't read {}: {}", path, why);
+            process::exit(1);
+        }
+    };
+
+    let mut computer = match Computer::init(&program) {
+        Ok(computer) => computer,
+        Err(why) => {
+            eprintln!("couldn't load {}: {}", path, why);
+            process::exit(1);
+        }
+    };
+    computer.enable_journal(JOURNAL_CAPACITY);
+
+    let mut debugger = Debugger::new(computer);
+    let mut last_command = String::new();
+    let stdin = io::stdin();
+
+    list(&debugger, 0, 1);
+
+    loop {
+        print!("(intcode) ");
+        io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+
+        if stdin.lock().read_line(&mut line).unwrap() == 0 {
+            break;
+        }
+
+        let command = match line.trim() {
+            "" => last_command.clone(),
+            command => command.to_string(),
+        };
+
+        if command.is_empty() {
+            continue;
+        }
+
+        match run_command(&mut debugger, &command) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(why) => println!("error: {}", why),
+        }
+
+        last_command = command;
+    }
+}
@@ -0,0 +1,37 @@
+use intcode::{listing, parse_program};
+use std::env;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+use std::process;
+
+fn read_program(path: Option<String>) -> io::Result<String> {
+    let mut s = String::new();
+
+    match path {
+        Some(path) => File::open(path)?.read_to_string(&mut s)?,
+        None => io::stdin().read_to_string(&mut s)?,
+    };
+
+    Ok(s)
+}
+
+fn main() {
+    let path = env::args().nth(1);
+
+    let program = match read_program(path) {
+        Ok(program) => program,
+        Err(why) => {
+            eprintln!("couldn't read program: {}", why);
+            process::exit(1);
+        }
+    };
+
+    match parse_program(&program) {
+        Ok(program) => print!("{}", listing(&program)),
+        Err(why) => {
+            eprintln!("couldn't parse program: {}", why);
+            process::exit(1);
+        }
+    }
+}
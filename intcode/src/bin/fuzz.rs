@@ -0,0 +1,70 @@
+use intcode::fuzz;
+use std::env;
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const USAGE: &str = "\
+usage: fuzz [options]
+    --seed <n>          seed for the program generator, the clock by default
+    --cases <n>         number of random programs to try, 1000 by default
+    --size <n>          most instructions in a program, 32 by default
+    --instructions <n>  instruction budget for every run, 10000 by default";
+
+struct Options {
+    seed: u64,
+    cases: usize,
+    size: usize,
+    instructions: u64,
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        seed: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs()),
+        cases: 1000,
+        size: 32,
+        instructions: 10_000,
+    };
+
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("{} needs a value", arg))?;
+        let number = || {
+            value
+                .parse()
+                .map_err(|_| format!("{} is not a number", value))
+        };
+
+        match arg.as_str() {
+            "--seed" => options.seed = number()? as u64,
+            "--cases" => options.cases = number()?,
+            "--size" => options.size = number()?.max(1),
+            "--instructions" => options.instructions = number()? as u64,
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+
+    Ok(options)
+}
+
+fn main() {
+    let options = parse_options(env::args().skip(1)).unwrap_or_else(|why| {
+        eprintln!("{}\n{}", why, USAGE);
+        process::exit(1);
+    });
+
+    println!("seed {}", options.seed);
+
+    match fuzz(
+        options.seed,
+        options.cases,
+        options.size,
+        options.instructions,
+    ) {
+        Ok(()) => println!("{} programs, no divergence", options.cases),
+        Err(divergence) => {
+            println!("{}", divergence);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use intcode::{Computer, Profiler, State, TraceEvent, TraceLog, Tracer};
+use std::env;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::process;
+use std::sync::{Arc, Mutex};
+
+const USAGE: &str = "\
+usage: trace <program> [options]
+    --input <values>  comma separated integers to feed the program
+    --ascii <text>    text to feed the program, a newline is appended
+    --log <path>      write every executed instruction to the file
+    --top <n>         number of hottest addresses to report, 20 by default
+    --self-modification
+                      also report writes to addresses that were executed";
+
+struct Options {
+    program: String,
+    input: Vec<isize>,
+    ascii: Option<String>,
+    log: Option<String>,
+    top: usize,
+    self_modification: bool,
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        program: String::new(),
+        input: Vec::new(),
+        ascii: None,
+        log: None,
+        top: 20,
+        self_modification: false,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+
+        match arg.as_str() {
+            "--input" => {
+                for word in value()?.split(',') {
+                    let word = word.trim();
+                    options.input.push(
+                        word.parse()
+                            .map_err(|_| format!("{} is not a number", word))?,
+                    );
+                }
+            }
+            "--self-modification" => options.self_modification = true,
+            "--ascii" => options.ascii = Some(value()?),
+            "--log" => options.log = Some(value()?),
+            "--top" => {
+                let top = value()?;
+                options.top = top
+                    .parse()
+                    .map_err(|_| format!("{} is not a number", top))?;
+            }
+            _ if options.program.is_empty() && !arg.starts_with("--") => options.program = arg,
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+
+    if options.program.is_empty() {
+        return Err("missing program".to_string());
+    }
+
+    Ok(options)
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let options = parse_options(env::args().skip(1))
+        .unwrap_or_else(|why| fail(format!("{}\n{}", why, USAGE)));
+
+    let mut program = String::new();
+
+    if let Err(why) =
+        File::open(&options.program).and_then(|mut file| file.read_to_string(&mut program))
+    {
+        fail(format!("couldn't read {}: {}", options.program, why));
+    }
+
+    let mut computer = Computer::init(&program)
+        .unwrap_or_else(|why| fail(format!("couldn't load {}: {}", options.program, why)));
+    let profiler = Arc::new(Mutex::new(Profiler::new()));
+    computer.set_tracer(profiler.clone());
+
+    let log = options.log.as_ref().map(|path| match File::create(path) {
+        Ok(file) => Arc::new(Mutex::new(TraceLog::new(BufWriter::new(file)))),
+        Err(why) => fail(format!("couldn't create {}: {}", path, why)),
+    });
+
+    if let Some(log) = &log {
+        let profiler = profiler.clone();
+        let log = log.clone();
+
+        computer.set_tracer(Arc::new(Mutex::new(move |event: &TraceEvent| {
+            profiler.lock().unwrap().trace(event);
+            log.lock().unwrap().trace(event);
+        })));
+    }
+
+    if options.self_modification {
+        computer.track_self_modification();
+    }
+
+    for &value in options.input.iter() {
+        computer.provide_input(value);
+    }
+
+    if let Some(text) = &options.ascii {
+        computer.provide_ascii_input(&format!("{}\n", text));
+    }
+
+    let state = computer
+        .compute()
+        .unwrap_or_else(|why| fail(why.to_string()));
+
+    if let Some(log) = &log {
+        if let Err(why) = log.lock().unwrap().finish() {
+            fail(format!("couldn't write the trace log: {}", why));
+        }
+    }
+
+    let outputs = computer.read_outputs();
+
+    if outputs.iter().all(|&c| c > 0 && c < 128) {
+        print!(
+            "{}",
+            outputs.iter().map(|&c| c as u8 as char).collect::<String>()
+        );
+    } else {
+        println!("{:?}", outputs);
+    }
+
+    if state == State::AwaitingInput {
+        println!("(stopped waiting for input)");
+    }
+
+    io::stdout().flush().unwrap();
+    eprint!("\n{}", profiler.lock().unwrap().report(options.top));
+
+    if let Some(report) = computer.modification_report() {
+        eprint!("\nself-modification:\n{}", report);
+    }
+}
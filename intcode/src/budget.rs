@@ -0,0 +1,30 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// Which part of a `Budget` ran out.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Limit {
+    Instructions,
+    Memory,
+    Output,
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Limit::Instructions => "instruction",
+            Limit::Memory => "memory",
+            Limit::Output => "output",
+        })
+    }
+}
+
+/// Limits on what a computer may do, `None` meaning no limit. Instructions
+/// and output values are used up as the program runs, while `memory` caps the
+/// addresses it may write to.
+#[derive(Eq, PartialEq, Copy, Clone, Default, Debug)]
+pub struct Budget {
+    pub instructions: Option<u64>,
+    pub memory: Option<usize>,
+    pub output: Option<usize>,
+}
@@ -0,0 +1,403 @@
+use crate::instruction::{Instruction, Mode, Opcode};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// How control leaves a basic block.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Exit {
+    Halt,
+    /// The next instruction starts another block.
+    FallThrough(usize),
+    Jump(usize),
+    Branch {
+        taken: usize,
+        not_taken: usize,
+    },
+    /// An unconditional jump right after storing its own return address on
+    /// the relative base stack.
+    Call {
+        target: usize,
+        return_to: usize,
+    },
+    /// An unconditional jump through a relative base stack slot.
+    Return,
+    /// A call through a function pointer.
+    IndirectCall {
+        return_to: usize,
+    },
+    /// A jump to an address only known at run time, and where control goes if
+    /// the jump isn't taken.
+    Indirect {
+        fall_through: Option<usize>,
+    },
+    /// Runs off the end of the program or into words that don't decode.
+    Invalid,
+}
+
+impl Exit {
+    pub fn successors(&self) -> Vec<usize> {
+        match *self {
+            Exit::FallThrough(next) | Exit::Jump(next) => vec![next],
+            Exit::Branch { taken, not_taken } => vec![taken, not_taken],
+            Exit::Call { target, return_to } => vec![target, return_to],
+            Exit::IndirectCall { return_to } => vec![return_to],
+            Exit::Indirect { fall_through } => fall_through.into_iter().collect(),
+            Exit::Halt | Exit::Return | Exit::Invalid => vec![],
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct BasicBlock {
+    pub start: usize,
+    pub instructions: Vec<(usize, Instruction)>,
+    pub exit: Exit,
+}
+
+impl BasicBlock {
+    /// The first address after the block.
+    pub fn end(&self) -> usize {
+        self.instructions
+            .last()
+            .map_or(self.start, |(address, instruction)| {
+                address + instruction.word_count()
+            })
+    }
+}
+
+/// The value an instruction stores in a relative base stack slot, if it is a
+/// constant.
+fn stored_constant(instruction: &Instruction) -> Option<isize> {
+    let params = &instruction.params;
+
+    if params.len() != 3
+        || params[2].mode != Mode::Relative
+        || params[..2]
+            .iter()
+            .any(|param| param.mode != Mode::Immediate)
+    {
+        return None;
+    }
+
+    match instruction.opcode {
+        Opcode::Add => params[0].value.checked_add(params[1].value),
+        Opcode::Multiply => params[0].value.checked_mul(params[1].value),
+        _ => None,
+    }
+}
+
+fn in_program(value: isize, len: usize) -> Option<usize> {
+    if value >= 0 && (value as usize) < len {
+        Some(value as usize)
+    } else {
+        None
+    }
+}
+
+/// The exit the instruction ends its block with, if any. `stored` holds the
+/// constants the block has stored on the stack so far.
+fn block_exit(
+    address: usize,
+    instruction: &Instruction,
+    stored: &[isize],
+    len: usize,
+) -> Option<Exit> {
+    let next = address + instruction.word_count();
+
+    match instruction.opcode {
+        Opcode::Halt => Some(Exit::Halt),
+        Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+            let condition = instruction.params[0];
+            let target = instruction.params[1];
+            let jumps_on_zero = instruction.opcode == Opcode::JumpIfFalse;
+            let fall_through = in_program(next as isize, len);
+
+            if condition.mode == Mode::Immediate {
+                if (condition.value == 0) != jumps_on_zero {
+                    return None;
+                }
+
+                Some(match (target.mode, in_program(target.value, len)) {
+                    (Mode::Immediate, Some(target)) if stored.contains(&(next as isize)) => {
+                        Exit::Call {
+                            target,
+                            return_to: next,
+                        }
+                    }
+                    (Mode::Immediate, Some(target)) => Exit::Jump(target),
+                    (Mode::Immediate, None) => Exit::Invalid,
+                    (Mode::Relative, _) => Exit::Return,
+                    (Mode::Position, _) if stored.contains(&(next as isize)) => {
+                        Exit::IndirectCall { return_to: next }
+                    }
+                    (Mode::Position, _) => Exit::Indirect { fall_through: None },
+                })
+            } else {
+                Some(
+                    match (target.mode, in_program(target.value, len), fall_through) {
+                        (Mode::Immediate, Some(taken), Some(not_taken)) => {
+                            Exit::Branch { taken, not_taken }
+                        }
+                        (Mode::Immediate, Some(taken), None) => Exit::Jump(taken),
+                        (Mode::Immediate, None, _) => match fall_through {
+                            Some(next) => Exit::FallThrough(next),
+                            None => Exit::Invalid,
+                        },
+                        _ => Exit::Indirect { fall_through },
+                    },
+                )
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Basic blocks reachable from address 0, found without running the program.
+/// Only immediate jump targets are followed; after a call, control is assumed
+/// to come back to the return address the caller stored.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct ControlFlowGraph {
+    blocks: BTreeMap<usize, BasicBlock>,
+}
+
+impl ControlFlowGraph {
+    pub fn build(program: &[isize]) -> ControlFlowGraph {
+        let len = program.len();
+        let mut code: BTreeMap<usize, Instruction> = BTreeMap::new();
+        let mut covered = vec![false; len];
+        let mut leaders = BTreeSet::new();
+        let mut queue = vec![0];
+
+        if len > 0 {
+            leaders.insert(0);
+        }
+
+        while let Some(start) = queue.pop() {
+            let mut address = start;
+            let mut stored = Vec::new();
+
+            while address < len {
+                if covered[address] {
+                    if address != start && code.contains_key(&address) {
+                        leaders.insert(address);
+                    }
+
+                    break;
+                }
+
+                let instruction = match Instruction::decode(&program[address..]) {
+                    Some(instruction) => instruction,
+                    None => break,
+                };
+                let next = address + instruction.word_count();
+
+                if next > len || covered[address..next].iter().any(|&c| c) {
+                    break;
+                }
+
+                for c in &mut covered[address..next] {
+                    *c = true;
+                }
+
+                let exit = block_exit(address, &instruction, &stored, len);
+                stored.extend(stored_constant(&instruction));
+                code.insert(address, instruction);
+
+                if let Some(exit) = exit {
+                    for successor in exit.successors() {
+                        if leaders.insert(successor) {
+                            queue.push(successor);
+                        }
+                    }
+
+                    break;
+                }
+
+                address = next;
+            }
+        }
+
+        let blocks = leaders
+            .iter()
+            .map(|&start| {
+                let mut instructions = Vec::new();
+                let mut stored = Vec::new();
+                let mut address = start;
+
+                let exit = loop {
+                    let instruction = match code.get(&address) {
+                        Some(instruction) => instruction.clone(),
+                        None => break Exit::Invalid,
+                    };
+                    let next = address + instruction.word_count();
+                    let exit = block_exit(address, &instruction, &stored, len);
+
+                    stored.extend(stored_constant(&instruction));
+                    instructions.push((address, instruction));
+
+                    match exit {
+                        Some(exit) => break exit,
+                        None if leaders.contains(&next) => break Exit::FallThrough(next),
+                        None => address = next,
+                    }
+                };
+
+                (
+                    start,
+                    BasicBlock {
+                        start,
+                        instructions,
+                        exit,
+                    },
+                )
+            })
+            .collect();
+
+        ControlFlowGraph { blocks }
+    }
+
+    pub fn blocks(&self) -> impl Iterator<Item = &BasicBlock> {
+        self.blocks.values()
+    }
+
+    pub fn block(&self, start: usize) -> Option<&BasicBlock> {
+        self.blocks.get(&start)
+    }
+
+    /// The entry point and every call target.
+    pub fn functions(&self) -> Vec<usize> {
+        let mut functions: BTreeSet<usize> = self
+            .blocks()
+            .filter_map(|block| match block.exit {
+                Exit::Call { target, .. } => Some(target),
+                _ => None,
+            })
+            .collect();
+
+        if !self.blocks.is_empty() {
+            functions.insert(0);
+        }
+
+        functions.into_iter().collect()
+    }
+
+    /// Addresses of jumps and calls whose target isn't known statically,
+    /// returns aside.
+    pub fn indirect_jumps(&self) -> Vec<usize> {
+        self.blocks()
+            .filter(|block| {
+                matches!(
+                    block.exit,
+                    Exit::Indirect { .. } | Exit::IndirectCall { .. }
+                )
+            })
+            .filter_map(|block| block.instructions.last().map(|(address, _)| *address))
+            .collect()
+    }
+
+    /// Graphviz DOT source, one box per block. Call edges are bold, edges to
+    /// return addresses dashed, and blocks ending in an indirect jump red.
+    pub fn to_dot(&self) -> String {
+        let mut dot =
+            String::from("digraph intcode {\n    node [shape=box, fontname=\"monospace\"];\n");
+
+        for block in self.blocks() {
+            let mut label: String = block
+                .instructions
+                .iter()
+                .map(|(address, instruction)| format!("{:04}: {}\\l", address, instruction))
+                .collect();
+
+            let style = match block.exit {
+                Exit::Halt => {
+                    label.push_str("halt\\l");
+                    ", peripheries=2"
+                }
+                Exit::Return => {
+                    label.push_str("return\\l");
+                    ""
+                }
+                Exit::Indirect { .. } => {
+                    label.push_str("indirect jump\\l");
+                    ", color=red"
+                }
+                Exit::IndirectCall { .. } => {
+                    label.push_str("indirect call\\l");
+                    ", color=red"
+                }
+                Exit::Invalid => {
+                    label.push_str("invalid\\l");
+                    ", color=gray"
+                }
+                _ => "",
+            };
+
+            dot.push_str(&format!(
+                "    b{} [label=\"{}\"{}];\n",
+                block.start, label, style
+            ));
+
+            let edges: Vec<(usize, &str)> = match block.exit {
+                Exit::FallThrough(next) | Exit::Jump(next) => vec![(next, "")],
+                Exit::Branch { taken, not_taken } => vec![
+                    (taken, " [label=\"taken\"]"),
+                    (not_taken, " [label=\"not taken\"]"),
+                ],
+                Exit::Call { target, return_to } => vec![
+                    (target, " [label=\"call\", style=bold]"),
+                    (return_to, " [label=\"return\", style=dashed]"),
+                ],
+                Exit::IndirectCall { return_to } => {
+                    vec![(return_to, " [label=\"return\", style=dashed]")]
+                }
+                Exit::Indirect {
+                    fall_through: Some(next),
+                } => vec![(next, " [label=\"not taken\"]")],
+                _ => vec![],
+            };
+
+            for (to, attributes) in edges {
+                dot.push_str(&format!("    b{} -> b{}{};\n", block.start, to, attributes));
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl Display for ControlFlowGraph {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for block in self.blocks() {
+            let exit = match block.exit {
+                Exit::Halt => "halt".to_string(),
+                Exit::FallThrough(next) => format!("falls through to {:04}", next),
+                Exit::Jump(target) => format!("jumps to {:04}", target),
+                Exit::Branch { taken, not_taken } => {
+                    format!("branches to {:04} or {:04}", taken, not_taken)
+                }
+                Exit::Call { target, return_to } => {
+                    format!("calls {:04}, returning to {:04}", target, return_to)
+                }
+                Exit::Return => "returns".to_string(),
+                Exit::IndirectCall { return_to } => {
+                    format!("calls indirectly, returning to {:04}", return_to)
+                }
+                Exit::Indirect {
+                    fall_through: Some(next),
+                } => format!("jumps indirectly or falls through to {:04}", next),
+                Exit::Indirect { fall_through: None } => "jumps indirectly".to_string(),
+                Exit::Invalid => "runs into invalid code".to_string(),
+            };
+
+            writeln!(f, "block {:04}..{:04}, {}", block.start, block.end(), exit)?;
+
+            for (address, instruction) in &block.instructions {
+                writeln!(f, "    {:04}: {}", address, instruction)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,314 @@
+use crate::computer::{Computer, State};
+use crate::error::{Fault, IntcodeError};
+use crate::instruction::Opcode;
+use crate::memory::Memory;
+use crate::word::Word;
+
+/// Where an input parameter's value comes from, resolved once at compile time.
+#[derive(Clone, Debug)]
+enum Source<W> {
+    Position(usize),
+    Immediate(W),
+    Relative(isize),
+}
+
+#[derive(Clone, Debug)]
+enum Target {
+    Position(usize),
+    Relative(isize),
+}
+
+#[derive(Clone, Debug)]
+enum Op<W> {
+    Add(Source<W>, Source<W>, Target),
+    Multiply(Source<W>, Source<W>, Target),
+    Input(Target),
+    Output(Source<W>),
+    JumpIfTrue(Source<W>, Source<W>),
+    JumpIfFalse(Source<W>, Source<W>),
+    LessThan(Source<W>, Source<W>, Target),
+    Equals(Source<W>, Source<W>, Target),
+    AdjustRelativeBase(Source<W>),
+    Halt,
+}
+
+impl<W> Op<W> {
+    fn len(&self) -> isize {
+        match self {
+            Op::Add(..) | Op::Multiply(..) | Op::LessThan(..) | Op::Equals(..) => 4,
+            Op::JumpIfTrue(..) | Op::JumpIfFalse(..) => 3,
+            Op::Input(_) | Op::Output(_) | Op::AdjustRelativeBase(_) => 2,
+            Op::Halt => 1,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Slot<W> {
+    /// Not decoded yet, or overwritten since it was.
+    Pending,
+    Compiled(Op<W>),
+    /// Can't be pre-decoded, or would fault; left to the interpreter.
+    Interpreted,
+}
+
+/// One slot per address, each holding the instruction that starts there.
+#[derive(Clone, Debug)]
+pub(crate) struct Code<W> {
+    slots: Vec<Slot<W>>,
+}
+
+fn decode_source<W: Word>(mode: isize, word: W) -> Option<Source<W>> {
+    match mode {
+        0 => word
+            .to_isize()
+            .filter(|&address| address >= 0)
+            .map(|address| Source::Position(address as usize)),
+        1 => Some(Source::Immediate(word)),
+        2 => word.to_isize().map(Source::Relative),
+        _ => None,
+    }
+}
+
+fn decode_target<W: Word>(mode: isize, word: W) -> Option<Target> {
+    match mode {
+        0 => word
+            .to_isize()
+            .filter(|&address| address >= 0)
+            .map(|address| Target::Position(address as usize)),
+        2 => word.to_isize().map(Target::Relative),
+        _ => None,
+    }
+}
+
+fn decode<M: Memory>(memory: &M, address: usize) -> Option<Op<M::Word>> {
+    let instruction = memory.read(address).to_isize().filter(|&i| i >= 0)?;
+    let opcode = Opcode::from_code(instruction % 100)?;
+    let mut modes = instruction / 100;
+
+    let params: Vec<(isize, M::Word)> = (1..=opcode.number_of_params())
+        .map(|offset| {
+            let mode = modes % 10;
+            modes /= 10;
+
+            (mode, memory.read(address + offset))
+        })
+        .collect();
+    let source = |i: usize| decode_source(params[i].0, params[i].1.clone());
+    let target = |i: usize| decode_target(params[i].0, params[i].1.clone());
+
+    Some(match opcode {
+        Opcode::Add => Op::Add(source(0)?, source(1)?, target(2)?),
+        Opcode::Multiply => Op::Multiply(source(0)?, source(1)?, target(2)?),
+        Opcode::Input => Op::Input(target(0)?),
+        Opcode::Output => Op::Output(source(0)?),
+        Opcode::JumpIfTrue => Op::JumpIfTrue(source(0)?, source(1)?),
+        Opcode::JumpIfFalse => Op::JumpIfFalse(source(0)?, source(1)?),
+        Opcode::LessThan => Op::LessThan(source(0)?, source(1)?, target(2)?),
+        Opcode::Equals => Op::Equals(source(0)?, source(1)?, target(2)?),
+        Opcode::AdjustRelativeBase => Op::AdjustRelativeBase(source(0)?),
+        Opcode::Halt => Op::Halt,
+    })
+}
+
+/// The code cache only grows this far past its end at once. Anything further
+/// is left to the interpreter, so a wild jump doesn't allocate a slot for
+/// every address before it.
+const MAX_GAP: usize = 1 << 12;
+
+impl<W: Word> Code<W> {
+    /// Decodes every address up to the last nonzero cell.
+    pub(crate) fn compile<M: Memory<Word = W>>(memory: &M) -> Code<W> {
+        let len = memory.cells().last().map_or(0, |&(address, _)| address + 1);
+
+        let slots = (0..len)
+            .map(|address| match decode(memory, address) {
+                Some(op) => Slot::Compiled(op),
+                None => Slot::Interpreted,
+            })
+            .collect();
+
+        Code { slots }
+    }
+
+    /// Drops every instruction that could include the cell at `address`.
+    pub(crate) fn invalidate(&mut self, address: usize) {
+        let end = (address + 1).min(self.slots.len());
+
+        for slot in &mut self.slots[address.saturating_sub(3).min(end)..end] {
+            *slot = Slot::Pending;
+        }
+    }
+
+    fn fetch<M: Memory<Word = W>>(&mut self, memory: &M, address: usize) -> Option<&Op<W>> {
+        if address >= self.slots.len() + MAX_GAP {
+            return None;
+        } else if address >= self.slots.len() {
+            self.slots.resize(address + 1, Slot::Pending);
+        }
+
+        if let Slot::Pending = self.slots[address] {
+            self.slots[address] = match decode(memory, address) {
+                Some(op) => Slot::Compiled(op),
+                None => Slot::Interpreted,
+            };
+        }
+
+        match &self.slots[address] {
+            Slot::Compiled(op) => Some(op),
+            _ => None,
+        }
+    }
+}
+
+impl<M: Memory> Computer<M> {
+    fn value_of(&self, source: &Source<M::Word>) -> Result<M::Word, Fault> {
+        match source {
+            Source::Position(address) => Ok(self.memory.read(*address)),
+            Source::Immediate(value) => Ok(value.clone()),
+            Source::Relative(offset) => Ok(self.memory.read(self.resolve(*offset)?)),
+        }
+    }
+
+    fn resolve(&self, offset: isize) -> Result<usize, Fault> {
+        match offset.checked_add(self.relative_base) {
+            Some(address) if address >= 0 => Ok(address as usize),
+            Some(address) => Err(Fault::NegativeAddress(address)),
+            None => Err(Fault::AddressOverflow),
+        }
+    }
+
+    fn target(&self, target: &Target) -> Result<usize, Fault> {
+        match target {
+            Target::Position(address) => Ok(*address),
+            Target::Relative(offset) => self.resolve(*offset),
+        }
+    }
+
+    fn jump_target(target: M::Word) -> Result<isize, Fault> {
+        match target.to_isize() {
+            Some(address) if address >= 0 => Ok(address),
+            Some(address) => Err(Fault::NegativeAddress(address)),
+            None => Err(Fault::AddressOverflow),
+        }
+    }
+
+    fn arithmetic(
+        &self,
+        a: &Source<M::Word>,
+        b: &Source<M::Word>,
+        add: bool,
+    ) -> Result<M::Word, Fault> {
+        let (a, b) = (self.value_of(a)?, self.value_of(b)?);
+
+        match (self.checked, add) {
+            (true, true) => a.checked_add(&b).ok_or(Fault::Overflow),
+            (true, false) => a.checked_mul(&b).ok_or(Fault::Overflow),
+            (false, true) => Ok(a.wrapping_add(&b)),
+            (false, false) => Ok(a.wrapping_mul(&b)),
+        }
+    }
+
+    /// Runs one pre-decoded instruction. Nothing changes if it faults, so the
+    /// interpreter can run it again to report the error.
+    fn run_op(&mut self, op: &Op<M::Word>) -> Result<Option<State>, Fault> {
+        let mut next = self.pointer + op.len();
+        let mut state = None;
+
+        match op {
+            Op::Add(a, b, target) | Op::Multiply(a, b, target) => {
+                let value = self.arithmetic(a, b, matches!(op, Op::Add(..)))?;
+                let address = self.target(target)?;
+                self.check_memory(address)?;
+                self.memory.write(address, value);
+                self.written = Some(address as isize);
+            }
+            Op::LessThan(a, b, target) | Op::Equals(a, b, target) => {
+                let (a, b) = (self.value_of(a)?, self.value_of(b)?);
+                let address = self.target(target)?;
+                self.check_memory(address)?;
+                let holds = if let Op::LessThan(..) = op {
+                    a < b
+                } else {
+                    a == b
+                };
+
+                self.memory
+                    .write(address, M::Word::from_isize(if holds { 1 } else { 0 }));
+                self.written = Some(address as isize);
+            }
+            Op::Input(target) => {
+                if self.input.is_empty() {
+                    return Ok(Some(State::AwaitingInput));
+                }
+
+                let address = self.target(target)?;
+                self.check_memory(address)?;
+                let value = self.input.pop_front().unwrap();
+                self.memory.write(address, value);
+                self.written = Some(address as isize);
+            }
+            Op::Output(source) => {
+                let value = self.value_of(source)?;
+                self.spend_output()?;
+                self.output.push_back(value);
+                state = Some(State::ProducedOutput);
+            }
+            Op::JumpIfTrue(condition, target) | Op::JumpIfFalse(condition, target) => {
+                let zero = self.value_of(condition)? == M::Word::default();
+                let target = self.value_of(target)?;
+
+                if zero == matches!(op, Op::JumpIfFalse(..)) {
+                    next = Self::jump_target(target)?;
+                }
+            }
+            Op::AdjustRelativeBase(source) => {
+                let offset = self
+                    .value_of(source)?
+                    .to_isize()
+                    .ok_or(Fault::AddressOverflow)?;
+                self.relative_base = self
+                    .relative_base
+                    .checked_add(offset)
+                    .ok_or(Fault::AddressOverflow)?;
+            }
+            Op::Halt => return Ok(Some(State::Finished)),
+        }
+
+        self.pointer = next;
+        Ok(state)
+    }
+
+    /// `execute` on pre-decoded instructions, interpreting whatever the code
+    /// cache can't handle.
+    pub(crate) fn execute_compiled(
+        &mut self,
+        mut code: Code<M::Word>,
+        pause_on_output: bool,
+    ) -> (Code<M::Word>, Result<State, IntcodeError>) {
+        loop {
+            let state = match code.fetch(&self.memory, self.pointer as usize) {
+                Some(op) if self.has_instructions_left() => match self.run_op(op) {
+                    Ok(state @ None) | Ok(state @ Some(State::ProducedOutput)) => {
+                        self.spend_instruction();
+                        Ok(state)
+                    }
+                    Ok(state) => Ok(state),
+                    Err(_) => self.step(),
+                },
+                _ => self.step(),
+            };
+
+            if let Some(address) = self.written.take() {
+                code.invalidate(address as usize);
+            }
+
+            match state {
+                Ok(Some(State::ProducedOutput)) if !pause_on_output => {}
+                Ok(Some(state)) => return (code, Ok(state)),
+                Ok(None) => {}
+                Err(error) => return (code, Err(error)),
+            }
+        }
+    }
+}
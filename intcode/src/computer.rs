@@ -0,0 +1,800 @@
+use crate::budget::{Budget, Limit};
+use crate::compiler::Code;
+use crate::error::{Fault, IntcodeError, ProgramError};
+use crate::instruction::Opcode;
+use crate::io::IntcodeIo;
+use crate::journal::{Change, Journal};
+use crate::memory::{FlatMemory, Memory};
+use crate::modification::{ModificationHook, ModificationReport, SelfModification};
+use crate::tracer::{TraceEvent, Tracer};
+use crate::word::Word;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum State {
+    Finished,
+    AwaitingInput,
+    ProducedOutput,
+    /// Part of the budget ran out before the instruction under the pointer
+    /// could run. Raising the budget lets the program carry on.
+    BudgetExhausted(Limit),
+}
+
+pub fn parse_program<W: Word>(program: &str) -> Result<Vec<W>, ProgramError> {
+    program
+        .trim()
+        .split(',')
+        .enumerate()
+        .map(|(position, x)| {
+            x.trim()
+                .parse::<W>()
+                .map_err(|_| ProgramError::InvalidWord {
+                    position,
+                    word: x.trim().to_string(),
+                })
+        })
+        .collect()
+}
+
+pub fn format_program<W: Word>(program: &[W]) -> String {
+    program
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[derive(Clone)]
+pub struct Computer<M: Memory = FlatMemory> {
+    pub(crate) memory: M,
+    initial_memory: M,
+    pub(crate) pointer: isize,
+    pub(crate) relative_base: isize,
+    pub(crate) input: VecDeque<M::Word>,
+    pub(crate) output: VecDeque<M::Word>,
+    pub(crate) checked: bool,
+    budget: Budget,
+    pub(crate) written: Option<isize>,
+    overwritten: Option<M::Word>,
+    /// Values read by the instruction being traced.
+    operands: Vec<M::Word>,
+    journal: Option<Journal<M::Word>>,
+    tracer: Option<Arc<Mutex<dyn Tracer<M::Word> + Send>>>,
+    code: Option<Code<M::Word>>,
+    initial_code: Option<Code<M::Word>>,
+    modifications: Option<ModificationReport>,
+    modification_hook: Option<ModificationHook<M::Word>>,
+}
+
+impl Computer {
+    pub fn init(program: &str) -> Result<Computer, ProgramError> {
+        Computer::load(program)
+    }
+}
+
+impl<M: Memory> Computer<M> {
+    /// Same as `Computer::init`, but for any memory backend.
+    pub fn load(program: &str) -> Result<Computer<M>, ProgramError> {
+        let memory = M::from_program(&parse_program(program)?);
+
+        Ok(Computer {
+            memory: memory.clone(),
+            initial_memory: memory,
+            pointer: 0,
+            relative_base: 0,
+            input: VecDeque::new(),
+            output: VecDeque::new(),
+            checked: false,
+            budget: Budget::default(),
+            written: None,
+            overwritten: None,
+            operands: Vec::new(),
+            journal: None,
+            tracer: None,
+            code: None,
+            initial_code: None,
+            modifications: None,
+            modification_hook: None,
+        })
+    }
+
+    pub(crate) fn from_state(
+        initial_memory: M,
+        memory: M,
+        pointer: isize,
+        relative_base: isize,
+        input: VecDeque<M::Word>,
+        output: VecDeque<M::Word>,
+    ) -> Computer<M> {
+        Computer {
+            memory,
+            initial_memory,
+            pointer,
+            relative_base,
+            input,
+            output,
+            checked: false,
+            budget: Budget::default(),
+            written: None,
+            overwritten: None,
+            operands: Vec::new(),
+            journal: None,
+            tracer: None,
+            code: None,
+            initial_code: None,
+            modifications: None,
+            modification_hook: None,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.memory = self.initial_memory.clone();
+        self.pointer = 0;
+        self.relative_base = 0;
+        self.input.clear();
+        self.output.clear();
+        self.code = self.initial_code.clone();
+
+        if let Some(journal) = &mut self.journal {
+            journal.clear();
+        }
+
+        if let Some(report) = &mut self.modifications {
+            *report = ModificationReport::default();
+        }
+    }
+
+    /// Pre-decodes the program so `compute`, `compute_until_output` and `run`
+    /// don't have to decode every instruction they execute. An instruction
+    /// whose words get overwritten is decoded again before it next runs, and
+    /// anything that can't be pre-decoded is interpreted as usual. Tracing,
+    /// journaling and tracking self-modification need the interpreter, so they
+    /// bypass the compiled code.
+    pub fn compile(&mut self) {
+        self.initial_code = Some(Code::compile(&self.initial_memory));
+        self.code = Some(Code::compile(&self.memory));
+    }
+
+    fn store(&mut self, address: usize, value: M::Word) {
+        self.memory.write(address, value);
+
+        if let Some(code) = &mut self.code {
+            code.invalidate(address);
+        }
+    }
+
+    pub(crate) fn memory(&self) -> &M {
+        &self.memory
+    }
+
+    pub(crate) fn initial_memory(&self) -> &M {
+        &self.initial_memory
+    }
+
+    pub fn read_memory(&self, address: usize) -> M::Word {
+        self.memory.read(address)
+    }
+
+    pub fn write_memory(&mut self, address: usize, value: M::Word) {
+        self.store(address, value);
+    }
+
+    pub fn pointer(&self) -> isize {
+        self.pointer
+    }
+
+    pub fn relative_base(&self) -> isize {
+        self.relative_base
+    }
+
+    pub fn pending_input(&self) -> &VecDeque<M::Word> {
+        &self.input
+    }
+
+    pub fn pending_output(&self) -> &VecDeque<M::Word> {
+        &self.output
+    }
+
+    /// Makes `add` and `mul` fail with `IntcodeError::Overflow` instead of
+    /// wrapping around when the result doesn't fit the word type.
+    pub fn set_checked_arithmetic(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+
+    /// Replaces the budget. `reset` leaves it alone.
+    pub fn set_budget(&mut self, budget: Budget) {
+        self.budget = budget;
+    }
+
+    /// What is left of the budget.
+    pub fn budget(&self) -> Budget {
+        self.budget
+    }
+
+    pub(crate) fn has_instructions_left(&self) -> bool {
+        self.budget.instructions != Some(0)
+    }
+
+    pub(crate) fn spend_instruction(&mut self) {
+        if let Some(left) = &mut self.budget.instructions {
+            *left -= 1;
+        }
+    }
+
+    pub(crate) fn spend_output(&mut self) -> Result<(), Fault> {
+        match &mut self.budget.output {
+            Some(0) => Err(Fault::Budget(Limit::Output)),
+            Some(left) => {
+                *left -= 1;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn check_memory(&self, address: usize) -> Result<(), Fault> {
+        match self.budget.memory {
+            Some(size) if address >= size => Err(Fault::Budget(Limit::Memory)),
+            _ if address >= M::LIMIT => Err(Fault::AddressOverflow),
+            _ => Ok(()),
+        }
+    }
+
+    /// Reports every executed instruction to the tracer. Clones of the computer
+    /// share it.
+    pub fn set_tracer<T: Tracer<M::Word> + Send + 'static>(&mut self, tracer: Arc<Mutex<T>>) {
+        self.tracer = Some(tracer);
+    }
+
+    pub fn clear_tracer(&mut self) {
+        self.tracer = None;
+    }
+
+    /// Starts recording which addresses get executed and which of those are
+    /// written to later on.
+    pub fn track_self_modification(&mut self) {
+        if self.modifications.is_none() {
+            self.modifications = Some(ModificationReport::default());
+        }
+    }
+
+    /// What has been recorded since tracking started or the last `reset`.
+    pub fn modification_report(&self) -> Option<&ModificationReport> {
+        self.modifications.as_ref()
+    }
+
+    /// Stops tracking and hands over the report.
+    pub fn take_modification_report(&mut self) -> Option<ModificationReport> {
+        self.modifications.take()
+    }
+
+    /// Calls `hook` on every write to an already executed address, and starts
+    /// tracking if it isn't on yet. Clones of the computer share the hook.
+    pub fn set_self_modification_hook<F>(&mut self, hook: F)
+    where
+        F: FnMut(&SelfModification<M::Word>) + Send + 'static,
+    {
+        self.track_self_modification();
+        self.modification_hook = Some(Arc::new(Mutex::new(hook)));
+    }
+
+    pub fn clear_self_modification_hook(&mut self) {
+        self.modification_hook = None;
+    }
+
+    /// Starts keeping an undo journal of the last `capacity` instructions, or
+    /// resizes the existing one.
+    pub fn enable_journal(&mut self, capacity: usize) {
+        match &mut self.journal {
+            Some(journal) => journal.set_capacity(capacity),
+            None => self.journal = Some(Journal::new(capacity)),
+        }
+    }
+
+    pub fn disable_journal(&mut self) {
+        self.journal = None;
+    }
+
+    /// Number of instructions that can currently be undone.
+    pub fn journal_len(&self) -> usize {
+        self.journal.as_ref().map_or(0, Journal::len)
+    }
+
+    fn undo(&mut self, change: Change<M::Word>) {
+        if let Some((address, value)) = change.overwritten {
+            self.store(address as usize, value);
+        }
+
+        if let Some(input) = change.input {
+            self.input.push_front(input);
+        }
+
+        if change.output {
+            self.output.pop_back();
+        }
+
+        self.pointer = change.pointer;
+        self.relative_base = change.relative_base;
+    }
+
+    /// Undoes up to `steps` instructions and returns how many were undone.
+    ///
+    /// Consumed input goes back to the front of the queue. Output that has
+    /// already been read can't be taken back, so undoing an `out` only drops
+    /// the value if it is still pending.
+    pub fn rewind(&mut self, steps: usize) -> usize {
+        let mut undone = 0;
+
+        while undone < steps {
+            match self.journal.as_mut().and_then(Journal::pop) {
+                Some(change) => self.undo(change),
+                None => break,
+            }
+
+            undone += 1;
+        }
+
+        undone
+    }
+
+    /// Undoes instructions up to and including the last input or output one.
+    pub fn rewind_to_io(&mut self) -> usize {
+        let mut undone = 0;
+
+        while let Some(change) = self.journal.as_mut().and_then(Journal::pop) {
+            let is_io = change.is_io();
+            self.undo(change);
+            undone += 1;
+
+            if is_io {
+                break;
+            }
+        }
+
+        undone
+    }
+
+    fn read(&self, address: isize) -> Result<M::Word, Fault> {
+        if address < 0 {
+            Err(Fault::NegativeAddress(address))
+        } else {
+            Ok(self.memory.read(address as usize))
+        }
+    }
+
+    fn write(&mut self, address: isize, value: M::Word) -> Result<(), Fault> {
+        if address < 0 {
+            Err(Fault::NegativeAddress(address))
+        } else {
+            self.check_memory(address as usize)?;
+
+            if self.journal.is_some() || self.modifications.is_some() {
+                self.overwritten = Some(self.memory.read(address as usize));
+            }
+
+            self.store(address as usize, value);
+            self.written = Some(address);
+            Ok(())
+        }
+    }
+
+    fn jump(&mut self, address: isize) -> Result<(), Fault> {
+        if address < 0 {
+            Err(Fault::NegativeAddress(address))
+        } else {
+            self.pointer = address;
+            Ok(())
+        }
+    }
+
+    fn address(word: &M::Word) -> Result<isize, Fault> {
+        word.to_isize().ok_or(Fault::AddressOverflow)
+    }
+
+    fn relative_address(&self, word: &M::Word) -> Result<isize, Fault> {
+        Self::address(word)?
+            .checked_add(self.relative_base)
+            .ok_or(Fault::AddressOverflow)
+    }
+
+    fn get_parameter_value(&self, parameter: M::Word, mode: isize) -> Result<M::Word, Fault> {
+        match mode {
+            0 => self.read(Self::address(&parameter)?),
+            1 => Ok(parameter),
+            2 => self.read(self.relative_address(&parameter)?),
+            x => Err(Fault::InvalidMode(x)),
+        }
+    }
+
+    fn get_input_params(
+        &mut self,
+        modes: &mut Vec<isize>,
+        number_of_params: usize,
+    ) -> Result<Vec<M::Word>, Fault> {
+        let mut params = Vec::new();
+
+        for _i in 0..number_of_params {
+            let param = self.read(self.pointer)?;
+
+            let value = self.get_parameter_value(
+                param,
+                if !modes.is_empty() {
+                    modes.remove(0)
+                } else {
+                    0
+                },
+            )?;
+
+            if self.tracer.is_some() {
+                self.operands.push(value.clone());
+            }
+
+            params.push(value);
+
+            self.pointer += 1;
+        }
+
+        Ok(params)
+    }
+
+    fn get_output_param(&mut self, modes: &mut Vec<isize>) -> Result<isize, Fault> {
+        let mode = if modes.is_empty() { 0 } else { modes.remove(0) };
+        let param = self.read(self.pointer)?;
+        self.pointer += 1;
+
+        match mode {
+            0 => Self::address(&param),
+            2 => self.relative_address(&param),
+            x => Err(Fault::InvalidMode(x)),
+        }
+    }
+
+    fn add(&mut self, modes: &mut Vec<isize>) -> Result<(), Fault> {
+        let input_params = self.get_input_params(modes, 2)?;
+        let output_param = self.get_output_param(modes)?;
+
+        let sum = if self.checked {
+            input_params[0]
+                .checked_add(&input_params[1])
+                .ok_or(Fault::Overflow)?
+        } else {
+            input_params[0].wrapping_add(&input_params[1])
+        };
+
+        self.write(output_param, sum)
+    }
+
+    fn multiply(&mut self, modes: &mut Vec<isize>) -> Result<(), Fault> {
+        let input_params = self.get_input_params(modes, 2)?;
+        let output_param = self.get_output_param(modes)?;
+
+        let product = if self.checked {
+            input_params[0]
+                .checked_mul(&input_params[1])
+                .ok_or(Fault::Overflow)?
+        } else {
+            input_params[0].wrapping_mul(&input_params[1])
+        };
+
+        self.write(output_param, product)
+    }
+
+    fn get_input(&mut self, modes: &mut Vec<isize>, input: M::Word) -> Result<(), Fault> {
+        let output_param = self.get_output_param(modes)?;
+
+        self.write(output_param, input)
+    }
+
+    fn calculate_output(&mut self, modes: &mut Vec<isize>) -> Result<(), Fault> {
+        let input_param = self.get_input_params(modes, 1)?.pop().unwrap();
+
+        self.spend_output()?;
+        self.output.push_back(input_param);
+        Ok(())
+    }
+
+    fn adjust_relative_base(&mut self, modes: &mut Vec<isize>) -> Result<(), Fault> {
+        let input_param = self.get_input_params(modes, 1)?.pop().unwrap();
+
+        self.relative_base = self.relative_address(&input_param)?;
+        Ok(())
+    }
+
+    fn jump_if_true(&mut self, modes: &mut Vec<isize>) -> Result<(), Fault> {
+        let input_params = self.get_input_params(modes, 2)?;
+
+        if input_params[0] != M::Word::default() {
+            self.jump(Self::address(&input_params[1])?)?;
+        }
+
+        Ok(())
+    }
+
+    fn jump_if_false(&mut self, modes: &mut Vec<isize>) -> Result<(), Fault> {
+        let input_params = self.get_input_params(modes, 2)?;
+
+        if input_params[0] == M::Word::default() {
+            self.jump(Self::address(&input_params[1])?)?;
+        }
+
+        Ok(())
+    }
+
+    fn less_than(&mut self, modes: &mut Vec<isize>) -> Result<(), Fault> {
+        let input_params = self.get_input_params(modes, 2)?;
+        let output_param = self.get_output_param(modes)?;
+
+        self.write(
+            output_param,
+            M::Word::from_isize(if input_params[0] < input_params[1] {
+                1
+            } else {
+                0
+            }),
+        )
+    }
+
+    fn equals(&mut self, modes: &mut Vec<isize>) -> Result<(), Fault> {
+        let input_params = self.get_input_params(modes, 2)?;
+        let output_param = self.get_output_param(modes)?;
+
+        self.write(
+            output_param,
+            M::Word::from_isize(if input_params[0] == input_params[1] {
+                1
+            } else {
+                0
+            }),
+        )
+    }
+
+    fn parse_instruction(instruction: isize) -> (isize, Vec<isize>) {
+        let opcode = instruction % 100;
+        let mut modes = Vec::new();
+
+        let mut modes_num = instruction / 100;
+
+        while modes_num > 0 {
+            modes.push(modes_num % 10);
+            modes_num /= 10;
+        }
+
+        (opcode, modes)
+    }
+
+    pub fn provide_input(&mut self, input: M::Word) {
+        self.input.push_back(input);
+    }
+
+    pub fn provide_ascii_input(&mut self, input: &str) {
+        for c in input.chars() {
+            self.provide_input(M::Word::from_isize(c as isize));
+        }
+    }
+
+    pub fn read_output(&mut self) -> Option<M::Word> {
+        self.output.pop_front()
+    }
+
+    pub fn read_outputs(&mut self) -> Vec<M::Word> {
+        self.output.drain(..).collect()
+    }
+
+    pub fn read_ascii_output(&mut self) -> String {
+        let mut buffer = String::new();
+
+        while let Some(c) = self.read_output() {
+            buffer.push(std::char::from_u32(c.to_isize().unwrap() as u32).unwrap());
+        }
+
+        buffer
+    }
+
+    /// Runs the program until it halts or needs more input.
+    pub fn compute(&mut self) -> Result<State, IntcodeError> {
+        self.execute(false)
+    }
+
+    /// Like `compute`, but also pauses after every value written to the output.
+    pub fn compute_until_output(&mut self) -> Result<State, IntcodeError> {
+        self.execute(true)
+    }
+
+    /// Like `compute`, but takes input from `io` once the computer's own queue
+    /// runs dry and hands every output to it as soon as it is produced.
+    pub fn compute_with_io<T: IntcodeIo<M::Word>>(
+        &mut self,
+        mut io: T,
+    ) -> Result<State, IntcodeError> {
+        for value in self.output.drain(..) {
+            io.write(value);
+        }
+
+        loop {
+            match self.step()? {
+                Some(State::AwaitingInput) => match io.read() {
+                    Some(value) => self.input.push_back(value),
+                    None => return Ok(State::AwaitingInput),
+                },
+                Some(State::ProducedOutput) => io.write(self.output.pop_back().unwrap()),
+                Some(state) => return Ok(state),
+                None => {}
+            }
+        }
+    }
+
+    /// Runs the program to the end, treating a request for more input or an
+    /// exhausted budget as an error.
+    pub fn run(&mut self) -> Result<(), IntcodeError> {
+        match self.compute()? {
+            State::AwaitingInput => Err(IntcodeError::InputExhausted {
+                pointer: self.pointer,
+                instruction: self.instruction_at(self.pointer)?,
+            }),
+            State::BudgetExhausted(limit) => Err(IntcodeError::BudgetExhausted {
+                pointer: self.pointer,
+                instruction: self.instruction_at(self.pointer)?,
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn instruction_at(&self, pointer: isize) -> Result<isize, IntcodeError> {
+        self.memory
+            .read(pointer as usize)
+            .to_isize()
+            .ok_or(IntcodeError::InstructionOverflow { pointer })
+    }
+
+    fn execute(&mut self, pause_on_output: bool) -> Result<State, IntcodeError> {
+        if self.tracer.is_none() && self.journal.is_none() && self.modifications.is_none() {
+            if let Some(code) = self.code.take() {
+                let (code, result) = self.execute_compiled(code, pause_on_output);
+                self.code = Some(code);
+
+                return result;
+            }
+        }
+
+        loop {
+            match self.step()? {
+                Some(State::ProducedOutput) if !pause_on_output => {}
+                Some(state) => return Ok(state),
+                None => {}
+            }
+        }
+    }
+
+    /// Executes a single instruction.
+    ///
+    /// Returns the state the computer paused in, if any: halting and waiting for
+    /// input leave the pointer on the instruction, so stepping again is a no-op.
+    pub fn step(&mut self) -> Result<Option<State>, IntcodeError> {
+        let pointer = self.pointer;
+        let instruction = self.instruction_at(pointer)?;
+        let (opcode, mut modes) = Self::parse_instruction(instruction);
+
+        if opcode != 99 && !self.has_instructions_left() {
+            return Ok(Some(State::BudgetExhausted(Limit::Instructions)));
+        }
+
+        let words = match self.tracer.as_ref().and_then(|_| Opcode::from_code(opcode)) {
+            Some(decoded) => (0..=decoded.number_of_params())
+                .map(|offset| self.memory.read(pointer as usize + offset))
+                .collect(),
+            None => Vec::new(),
+        };
+        let relative_base = self.relative_base;
+        let mut consumed = None;
+        self.written = None;
+        self.operands.clear();
+        self.pointer += 1;
+
+        let result = match opcode {
+            1 => self.add(&mut modes),
+            2 => self.multiply(&mut modes),
+            3 => {
+                if let Some(input) = self.input.pop_front() {
+                    let result = self.get_input(&mut modes, input.clone());
+
+                    if result.is_err() {
+                        self.input.push_front(input);
+                    } else if self.journal.is_some() {
+                        consumed = Some(input);
+                    }
+
+                    result
+                } else {
+                    self.pointer = pointer;
+                    return Ok(Some(State::AwaitingInput));
+                }
+            }
+            4 => self.calculate_output(&mut modes),
+            5 => self.jump_if_true(&mut modes),
+            6 => self.jump_if_false(&mut modes),
+            7 => self.less_than(&mut modes),
+            8 => self.equals(&mut modes),
+            9 => self.adjust_relative_base(&mut modes),
+            99 => {
+                self.pointer = pointer;
+                return Ok(Some(State::Finished));
+            }
+            _ => {
+                self.pointer = pointer;
+                return Err(IntcodeError::InvalidOpcode {
+                    pointer,
+                    instruction,
+                });
+            }
+        };
+
+        if let Err(fault) = result {
+            self.pointer = pointer;
+
+            return match fault {
+                Fault::Budget(limit) => Ok(Some(State::BudgetExhausted(limit))),
+                fault => Err(fault.at(pointer, instruction)),
+            };
+        }
+
+        self.spend_instruction();
+
+        let overwritten = match (self.written, self.overwritten.take()) {
+            (Some(address), Some(value)) => Some((address, value)),
+            _ => None,
+        };
+
+        if let Some(report) = &mut self.modifications {
+            let len = Opcode::from_code(opcode).unwrap().number_of_params() + 1;
+            report.execute(pointer as usize, len);
+
+            if let Some((address, old)) = &overwritten {
+                let address = *address as usize;
+
+                if report.is_executed(address) {
+                    let new = self.memory.read(address);
+                    report.write(pointer, address, *old != new);
+
+                    if let Some(hook) = &self.modification_hook {
+                        (hook.lock().unwrap())(&SelfModification {
+                            pointer,
+                            address,
+                            old: old.clone(),
+                            new,
+                        });
+                    }
+                }
+            }
+        }
+
+        if let Some(journal) = &mut self.journal {
+            journal.record(Change {
+                pointer,
+                relative_base,
+                overwritten,
+                input: consumed,
+                output: opcode == 4,
+            });
+        }
+
+        if let Some(tracer) = &self.tracer {
+            tracer.lock().unwrap().trace(&TraceEvent {
+                pointer,
+                relative_base: self.relative_base,
+                words,
+                operands: std::mem::take(&mut self.operands),
+                write: self
+                    .written
+                    .map(|address| (address, self.memory.read(address as usize))),
+            });
+        }
+
+        if opcode == 4 {
+            Ok(Some(State::ProducedOutput))
+        } else {
+            Ok(None)
+        }
+    }
+}
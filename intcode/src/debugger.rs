@@ -0,0 +1,200 @@
+use crate::budget::Limit;
+use crate::computer::{Computer, State};
+use crate::error::IntcodeError;
+use crate::instruction::Opcode;
+use crate::memory::{FlatMemory, Memory};
+use crate::word::Word;
+use std::collections::{HashMap, HashSet};
+
+/// Why the debugger handed control back.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Stop<W = isize> {
+    Stepped,
+    Output(W),
+    Breakpoint(usize),
+    OpcodeBreakpoint(Opcode),
+    Watchpoint { address: usize, old: W, new: W },
+    AwaitingInput,
+    BudgetExhausted(Limit),
+    Finished,
+}
+
+pub struct Debugger<M: Memory = FlatMemory> {
+    computer: Computer<M>,
+    breakpoints: HashSet<usize>,
+    opcode_breakpoints: HashSet<Opcode>,
+    watchpoints: HashMap<usize, M::Word>,
+}
+
+impl<M: Memory> Debugger<M> {
+    pub fn new(computer: Computer<M>) -> Debugger<M> {
+        Debugger {
+            computer,
+            breakpoints: HashSet::new(),
+            opcode_breakpoints: HashSet::new(),
+            watchpoints: HashMap::new(),
+        }
+    }
+
+    pub fn computer(&self) -> &Computer<M> {
+        &self.computer
+    }
+
+    pub fn computer_mut(&mut self) -> &mut Computer<M> {
+        &mut self.computer
+    }
+
+    pub fn add_breakpoint(&mut self, address: usize) {
+        self.breakpoints.insert(address);
+    }
+
+    pub fn remove_breakpoint(&mut self, address: usize) -> bool {
+        self.breakpoints.remove(&address)
+    }
+
+    pub fn add_opcode_breakpoint(&mut self, opcode: Opcode) {
+        self.opcode_breakpoints.insert(opcode);
+    }
+
+    pub fn remove_opcode_breakpoint(&mut self, opcode: Opcode) -> bool {
+        self.opcode_breakpoints.remove(&opcode)
+    }
+
+    pub fn add_watchpoint(&mut self, address: usize) {
+        let value = self.computer.read_memory(address);
+        self.watchpoints.insert(address, value);
+    }
+
+    pub fn remove_watchpoint(&mut self, address: usize) -> bool {
+        self.watchpoints.remove(&address).is_some()
+    }
+
+    pub fn breakpoints(&self) -> Vec<usize> {
+        let mut breakpoints: Vec<usize> = self.breakpoints.iter().cloned().collect();
+        breakpoints.sort_unstable();
+        breakpoints
+    }
+
+    pub fn opcode_breakpoints(&self) -> Vec<Opcode> {
+        let mut opcodes: Vec<Opcode> = self.opcode_breakpoints.iter().cloned().collect();
+        opcodes.sort_unstable_by_key(|&opcode| opcode as isize);
+        opcodes
+    }
+
+    pub fn watchpoints(&self) -> Vec<usize> {
+        let mut watchpoints: Vec<usize> = self.watchpoints.keys().cloned().collect();
+        watchpoints.sort_unstable();
+        watchpoints
+    }
+
+    /// Writes to memory without tripping the watchpoint on that cell.
+    pub fn poke(&mut self, address: usize, value: M::Word) {
+        if let Some(watched) = self.watchpoints.get_mut(&address) {
+            *watched = value.clone();
+        }
+
+        self.computer.write_memory(address, value);
+    }
+
+    fn changed_watchpoint(&mut self) -> Option<Stop<M::Word>> {
+        let computer = &self.computer;
+
+        for (&address, old) in self.watchpoints.iter_mut() {
+            let new = computer.read_memory(address);
+
+            if new != *old {
+                let stop = Stop::Watchpoint {
+                    address,
+                    old: old.clone(),
+                    new: new.clone(),
+                };
+                *old = new;
+
+                return Some(stop);
+            }
+        }
+
+        None
+    }
+
+    /// Executes one instruction, ignoring breakpoints.
+    pub fn step(&mut self) -> Result<Stop<M::Word>, IntcodeError> {
+        let state = self.computer.step()?;
+
+        if let Some(stop) = self.changed_watchpoint() {
+            return Ok(stop);
+        }
+
+        Ok(match state {
+            Some(State::Finished) => Stop::Finished,
+            Some(State::AwaitingInput) => Stop::AwaitingInput,
+            Some(State::BudgetExhausted(limit)) => Stop::BudgetExhausted(limit),
+            Some(State::ProducedOutput) => {
+                Stop::Output(self.computer.pending_output().back().unwrap().clone())
+            }
+            None => Stop::Stepped,
+        })
+    }
+
+    fn refresh_watchpoints(&mut self) {
+        let computer = &self.computer;
+
+        for (&address, value) in self.watchpoints.iter_mut() {
+            *value = computer.read_memory(address);
+        }
+    }
+
+    /// Undoes up to `steps` instructions, as far as the computer's journal
+    /// allows, and returns how many were undone.
+    pub fn step_back(&mut self, steps: usize) -> usize {
+        let undone = self.computer.rewind(steps);
+        self.refresh_watchpoints();
+        undone
+    }
+
+    /// Restarts the program, keeping breakpoints and watchpoints.
+    pub fn reset(&mut self) {
+        self.computer.reset();
+        self.refresh_watchpoints();
+    }
+
+    /// Goes back to just before the last input or output instruction.
+    pub fn rewind_to_io(&mut self) -> usize {
+        let undone = self.computer.rewind_to_io();
+        self.refresh_watchpoints();
+        undone
+    }
+
+    fn breakpoint_at_pointer(&self) -> Option<Stop<M::Word>> {
+        let pointer = self.computer.pointer() as usize;
+
+        if self.breakpoints.contains(&pointer) {
+            return Some(Stop::Breakpoint(pointer));
+        }
+
+        let instruction = self.computer.read_memory(pointer).to_isize();
+
+        match instruction.and_then(|instruction| Opcode::from_code(instruction % 100)) {
+            Some(opcode) if self.opcode_breakpoints.contains(&opcode) => {
+                Some(Stop::OpcodeBreakpoint(opcode))
+            }
+            _ => None,
+        }
+    }
+
+    /// Runs until a breakpoint or watchpoint is hit, or the program halts or
+    /// needs input. The instruction under the pointer always runs first, so
+    /// continuing from a breakpoint doesn't stop on it again.
+    pub fn resume(&mut self) -> Result<Stop<M::Word>, IntcodeError> {
+        loop {
+            match self.step()? {
+                Stop::Stepped | Stop::Output(_) => {}
+                stop => return Ok(stop),
+            }
+
+            if let Some(stop) = self.breakpoint_at_pointer() {
+                return Ok(stop);
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::instruction::{Instruction, Mode, Opcode};
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+const DATA_WORDS_PER_LINE: usize = 8;
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum Entry {
+    Code(Instruction),
+    Data(Vec<isize>),
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Line {
+    pub address: usize,
+    pub entry: Entry,
+}
+
+impl Line {
+    pub fn words(&self) -> Vec<isize> {
+        match &self.entry {
+            Entry::Code(instruction) => instruction.encode(),
+            Entry::Data(words) => words.clone(),
+        }
+    }
+}
+
+fn join(words: &[isize], separator: &str) -> String {
+    words
+        .iter()
+        .map(|word| word.to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+impl Display for Line {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.entry {
+            Entry::Code(instruction) => write!(
+                f,
+                "{:04}: {:<32}; {}",
+                self.address,
+                instruction.to_string(),
+                join(&instruction.encode(), ",")
+            ),
+            Entry::Data(words) => write!(f, "{:04}: .data {}", self.address, join(words, ", ")),
+        }
+    }
+}
+
+/// Addresses control can continue to after the instruction, as far as is known
+/// without running the program. Jumps through memory are not followed.
+fn successors(address: usize, instruction: &Instruction) -> Vec<isize> {
+    let next = (address + instruction.word_count()) as isize;
+
+    match instruction.opcode {
+        Opcode::Halt => vec![],
+        Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+            let condition = instruction.params[0];
+            let target = instruction.params[1];
+            let jumps_on_zero = instruction.opcode == Opcode::JumpIfFalse;
+
+            let (may_jump, may_fall_through) = if condition.mode == Mode::Immediate {
+                let taken = (condition.value == 0) == jumps_on_zero;
+                (taken, !taken)
+            } else {
+                (true, true)
+            };
+
+            let mut successors = Vec::new();
+
+            if may_fall_through {
+                successors.push(next);
+            }
+
+            if may_jump && target.mode == Mode::Immediate {
+                successors.push(target.value);
+            }
+
+            successors
+        }
+        _ => vec![next],
+    }
+}
+
+/// Finds the instructions reachable from address 0 by following fall-through
+/// and immediate jump targets.
+fn find_code(program: &[isize]) -> Vec<Option<Instruction>> {
+    let mut code = vec![None; program.len()];
+    let mut covered = HashSet::new();
+    let mut queue = vec![0];
+
+    while let Some(address) = queue.pop() {
+        if address < 0 || address as usize >= program.len() {
+            continue;
+        }
+
+        let address = address as usize;
+
+        if covered.contains(&address) {
+            continue;
+        }
+
+        let instruction = match Instruction::decode(&program[address..]) {
+            Some(instruction) => instruction,
+            None => continue,
+        };
+
+        if (address..address + instruction.word_count()).any(|a| covered.contains(&a)) {
+            continue;
+        }
+
+        covered.extend(address..address + instruction.word_count());
+        queue.extend(successors(address, &instruction));
+        code[address] = Some(instruction);
+    }
+
+    code
+}
+
+pub fn disassemble(program: &[isize]) -> Vec<Line> {
+    let code = find_code(program);
+    let mut lines: Vec<Line> = Vec::new();
+    let mut address = 0;
+
+    while address < program.len() {
+        if let Some(instruction) = &code[address] {
+            lines.push(Line {
+                address,
+                entry: Entry::Code(instruction.clone()),
+            });
+
+            address += instruction.word_count();
+            continue;
+        }
+
+        match lines.last_mut() {
+            Some(Line {
+                entry: Entry::Data(words),
+                ..
+            }) if words.len() < DATA_WORDS_PER_LINE => words.push(program[address]),
+            _ => lines.push(Line {
+                address,
+                entry: Entry::Data(vec![program[address]]),
+            }),
+        }
+
+        address += 1;
+    }
+
+    lines
+}
+
+pub fn listing(program: &[isize]) -> String {
+    disassemble(program)
+        .iter()
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
@@ -0,0 +1,295 @@
+use crate::budget::Limit;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum IntcodeError {
+    InvalidOpcode {
+        pointer: isize,
+        instruction: isize,
+    },
+    InvalidMode {
+        pointer: isize,
+        instruction: isize,
+        mode: isize,
+    },
+    NegativeAddress {
+        pointer: isize,
+        instruction: isize,
+        address: isize,
+    },
+    InputExhausted {
+        pointer: isize,
+        instruction: isize,
+    },
+    Overflow {
+        pointer: isize,
+        instruction: isize,
+    },
+    AddressOverflow {
+        pointer: isize,
+        instruction: isize,
+    },
+    /// The word under the pointer is too large to be an instruction at all.
+    InstructionOverflow {
+        pointer: isize,
+    },
+    BudgetExhausted {
+        pointer: isize,
+        instruction: isize,
+        limit: Limit,
+    },
+}
+
+impl IntcodeError {
+    pub fn pointer(&self) -> isize {
+        match *self {
+            IntcodeError::InvalidOpcode { pointer, .. }
+            | IntcodeError::InvalidMode { pointer, .. }
+            | IntcodeError::NegativeAddress { pointer, .. }
+            | IntcodeError::InputExhausted { pointer, .. }
+            | IntcodeError::Overflow { pointer, .. }
+            | IntcodeError::AddressOverflow { pointer, .. }
+            | IntcodeError::InstructionOverflow { pointer }
+            | IntcodeError::BudgetExhausted { pointer, .. } => pointer,
+        }
+    }
+
+    /// The raw instruction, unless it was too large to read.
+    pub fn instruction(&self) -> Option<isize> {
+        match *self {
+            IntcodeError::InvalidOpcode { instruction, .. }
+            | IntcodeError::InvalidMode { instruction, .. }
+            | IntcodeError::NegativeAddress { instruction, .. }
+            | IntcodeError::InputExhausted { instruction, .. }
+            | IntcodeError::Overflow { instruction, .. }
+            | IntcodeError::AddressOverflow { instruction, .. }
+            | IntcodeError::BudgetExhausted { instruction, .. } => Some(instruction),
+            IntcodeError::InstructionOverflow { .. } => None,
+        }
+    }
+}
+
+impl Display for IntcodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            IntcodeError::InvalidOpcode {
+                pointer,
+                instruction,
+            } => write!(f, "unknown opcode in {} at {}", instruction, pointer),
+            IntcodeError::InvalidMode {
+                pointer,
+                instruction,
+                mode,
+            } => write!(
+                f,
+                "{} is a wrong parameter mode in {} at {}",
+                mode, instruction, pointer
+            ),
+            IntcodeError::NegativeAddress {
+                pointer,
+                instruction,
+                address,
+            } => write!(
+                f,
+                "negative address {} accessed by {} at {}",
+                address, instruction, pointer
+            ),
+            IntcodeError::InputExhausted {
+                pointer,
+                instruction,
+            } => write!(f, "no input left for {} at {}", instruction, pointer),
+            IntcodeError::Overflow {
+                pointer,
+                instruction,
+            } => write!(f, "arithmetic overflow in {} at {}", instruction, pointer),
+            IntcodeError::AddressOverflow {
+                pointer,
+                instruction,
+            } => write!(
+                f,
+                "address out of range accessed by {} at {}",
+                instruction, pointer
+            ),
+            IntcodeError::InstructionOverflow { pointer } => {
+                write!(f, "instruction at {} is too large to decode", pointer)
+            }
+            IntcodeError::BudgetExhausted {
+                pointer,
+                instruction,
+                limit,
+            } => write!(
+                f,
+                "{} budget exhausted before {} at {}",
+                limit, instruction, pointer
+            ),
+        }
+    }
+}
+
+impl Error for IntcodeError {}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum ProgramError {
+    InvalidWord { position: usize, word: String },
+}
+
+impl Display for ProgramError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgramError::InvalidWord { position, word } => {
+                write!(f, "word {}: {:?} is not a valid word", position, word)
+            }
+        }
+    }
+}
+
+impl Error for ProgramError {}
+
+/// A fault raised while executing an instruction, before the faulting
+/// pointer and instruction are known.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub(crate) enum Fault {
+    InvalidMode(isize),
+    NegativeAddress(isize),
+    Overflow,
+    AddressOverflow,
+    Budget(Limit),
+}
+
+impl Fault {
+    pub(crate) fn at(self, pointer: isize, instruction: isize) -> IntcodeError {
+        match self {
+            Fault::InvalidMode(mode) => IntcodeError::InvalidMode {
+                pointer,
+                instruction,
+                mode,
+            },
+            Fault::NegativeAddress(address) => IntcodeError::NegativeAddress {
+                pointer,
+                instruction,
+                address,
+            },
+            Fault::Overflow => IntcodeError::Overflow {
+                pointer,
+                instruction,
+            },
+            Fault::AddressOverflow => IntcodeError::AddressOverflow {
+                pointer,
+                instruction,
+            },
+            Fault::Budget(limit) => IntcodeError::BudgetExhausted {
+                pointer,
+                instruction,
+                limit,
+            },
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum AssemblyError {
+    Syntax {
+        line: usize,
+        text: String,
+    },
+    UnknownMnemonic {
+        line: usize,
+        mnemonic: String,
+    },
+    WrongOperandCount {
+        line: usize,
+        mnemonic: String,
+        expected: usize,
+        found: usize,
+    },
+    ImmediateOutput {
+        line: usize,
+    },
+    UnknownLabel {
+        line: usize,
+        label: String,
+    },
+    DuplicateLabel {
+        line: usize,
+        label: String,
+    },
+}
+
+impl AssemblyError {
+    pub fn line(&self) -> usize {
+        match *self {
+            AssemblyError::Syntax { line, .. }
+            | AssemblyError::UnknownMnemonic { line, .. }
+            | AssemblyError::WrongOperandCount { line, .. }
+            | AssemblyError::ImmediateOutput { line }
+            | AssemblyError::UnknownLabel { line, .. }
+            | AssemblyError::DuplicateLabel { line, .. } => line,
+        }
+    }
+}
+
+impl Display for AssemblyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AssemblyError::Syntax { line, text } => {
+                write!(f, "line {}: can't parse {:?}", line, text)
+            }
+            AssemblyError::UnknownMnemonic { line, mnemonic } => {
+                write!(f, "line {}: unknown mnemonic {:?}", line, mnemonic)
+            }
+            AssemblyError::WrongOperandCount {
+                line,
+                mnemonic,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: {} takes {} operands, found {}",
+                line, mnemonic, expected, found
+            ),
+            AssemblyError::ImmediateOutput { line } => {
+                write!(f, "line {}: can't write to an immediate operand", line)
+            }
+            AssemblyError::UnknownLabel { line, label } => {
+                write!(f, "line {}: unknown label {:?}", line, label)
+            }
+            AssemblyError::DuplicateLabel { line, label } => {
+                write!(f, "line {}: label {:?} is already defined", line, label)
+            }
+        }
+    }
+}
+
+impl Error for AssemblyError {}
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(io::Error),
+    UnsupportedVersion(String),
+    Malformed { line: usize, text: String },
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Io(error) => write!(f, "{}", error),
+            SnapshotError::UnsupportedVersion(header) => {
+                write!(f, "unsupported snapshot version: {:?}", header)
+            }
+            SnapshotError::Malformed { line, text } => {
+                write!(f, "line {}: can't parse {:?}", line, text)
+            }
+        }
+    }
+}
+
+impl Error for SnapshotError {}
+
+impl From<io::Error> for SnapshotError {
+    fn from(error: io::Error) -> SnapshotError {
+        SnapshotError::Io(error)
+    }
+}
@@ -0,0 +1,340 @@
+use crate::budget::Budget;
+use crate::computer::{format_program, Computer, State};
+use crate::error::IntcodeError;
+use crate::instruction::{Opcode, OPCODES};
+use crate::memory::{FlatMemory, Memory, SparseMemory};
+use crate::tracer::TraceEvent;
+use crate::word::Word;
+use num_bigint::BigInt;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+
+/// Addresses written past this are refused, so runaway relative bases don't
+/// allocate huge flat memories.
+const MEMORY_LIMIT: usize = 1 << 14;
+const OUTPUT_LIMIT: usize = 1 << 10;
+
+/// A xorshift64* generator, good enough to pick instructions and small enough
+/// to keep the fuzzer free of dependencies.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng {
+            state: seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in `low..high`.
+    pub fn range(&mut self, low: isize, high: isize) -> isize {
+        low + self.below((high - low) as usize) as isize
+    }
+}
+
+fn random_value(rng: &mut Rng) -> isize {
+    if rng.below(16) == 0 {
+        rng.range(-1 << 40, 1 << 40)
+    } else {
+        rng.range(-20, 20)
+    }
+}
+
+/// `instructions` random instructions followed by a halt. Every instruction
+/// decodes, but parameters point anywhere near the program, so it can still
+/// jump into data or overwrite itself.
+pub fn random_program(rng: &mut Rng, instructions: usize) -> Vec<isize> {
+    let bound = instructions * 4 + 8;
+    let mut program = Vec::new();
+
+    for _ in 0..instructions {
+        let opcode = OPCODES[rng.below(OPCODES.len())];
+        let number_of_params = opcode.number_of_params();
+        let mut instruction = opcode as isize;
+        let mut params = Vec::new();
+        let mut scale = 100;
+
+        for i in 0..number_of_params {
+            let is_output = opcode.writes_memory() && i == number_of_params - 1;
+            let is_target =
+                i == 1 && (opcode == Opcode::JumpIfTrue || opcode == Opcode::JumpIfFalse);
+            let mode = if is_output {
+                [0, 2][rng.below(2)]
+            } else {
+                rng.below(3) as isize
+            };
+
+            params.push(match mode {
+                0 => rng.below(bound) as isize,
+                1 if is_target => rng.below(bound) as isize,
+                1 => random_value(rng),
+                _ => rng.range(-8, 8),
+            });
+
+            instruction += mode * scale;
+            scale *= 10;
+        }
+
+        program.push(instruction);
+        program.extend(params);
+    }
+
+    program.push(Opcode::Halt as isize);
+    program
+}
+
+pub fn random_input(rng: &mut Rng, len: usize) -> Vec<isize> {
+    (0..len).map(|_| random_value(rng)).collect()
+}
+
+/// How a run ended, with words formatted so any word type compares.
+#[derive(Eq, PartialEq, Clone, Debug)]
+struct Outcome {
+    state: Result<State, IntcodeError>,
+    output: Vec<String>,
+    memory: Vec<(usize, String)>,
+}
+
+fn run<M: Memory>(
+    program: &[isize],
+    input: &[isize],
+    instructions: u64,
+    setup: fn(&mut Computer<M>),
+) -> Outcome {
+    let mut computer: Computer<M> = Computer::load(&format_program(program)).unwrap();
+
+    computer.set_checked_arithmetic(true);
+    computer.set_budget(Budget {
+        instructions: Some(instructions),
+        memory: Some(MEMORY_LIMIT),
+        output: Some(OUTPUT_LIMIT),
+    });
+    setup(&mut computer);
+
+    for &value in input {
+        computer.provide_input(M::Word::from_isize(value));
+    }
+
+    let state = computer.compute();
+
+    Outcome {
+        state,
+        output: computer
+            .read_outputs()
+            .iter()
+            .map(|value| value.to_string())
+            .collect(),
+        memory: computer
+            .memory()
+            .cells()
+            .into_iter()
+            .map(|(address, value)| (address, value.to_string()))
+            .collect(),
+    }
+}
+
+struct Implementation {
+    name: &'static str,
+    /// Words wider than `isize` don't overflow where it does.
+    wide: bool,
+    run: fn(&[isize], &[isize], u64) -> Outcome,
+}
+
+/// The reference comes first.
+const IMPLEMENTATIONS: [Implementation; 10] = [
+    Implementation {
+        name: "flat",
+        wide: false,
+        run: |program, input, budget| run::<FlatMemory>(program, input, budget, |_| {}),
+    },
+    Implementation {
+        name: "sparse",
+        wide: false,
+        run: |program, input, budget| run::<SparseMemory>(program, input, budget, |_| {}),
+    },
+    Implementation {
+        name: "flat compiled",
+        wide: false,
+        run: |program, input, budget| {
+            run::<FlatMemory>(program, input, budget, |computer| computer.compile())
+        },
+    },
+    Implementation {
+        name: "sparse compiled",
+        wide: false,
+        run: |program, input, budget| {
+            run::<SparseMemory>(program, input, budget, |computer| computer.compile())
+        },
+    },
+    Implementation {
+        name: "journaled",
+        wide: false,
+        run: |program, input, budget| {
+            run::<FlatMemory>(program, input, budget, |computer| {
+                computer.enable_journal(16)
+            })
+        },
+    },
+    Implementation {
+        name: "traced",
+        wide: false,
+        run: |program, input, budget| {
+            run::<FlatMemory>(program, input, budget, |computer| {
+                computer.set_tracer(Arc::new(Mutex::new(|_: &TraceEvent| {})))
+            })
+        },
+    },
+    Implementation {
+        name: "i64 compiled",
+        wide: false,
+        run: |program, input, budget| {
+            run::<FlatMemory<i64>>(program, input, budget, |computer| computer.compile())
+        },
+    },
+    Implementation {
+        name: "i128",
+        wide: true,
+        run: |program, input, budget| run::<FlatMemory<i128>>(program, input, budget, |_| {}),
+    },
+    Implementation {
+        name: "bigint",
+        wide: true,
+        run: |program, input, budget| run::<SparseMemory<BigInt>>(program, input, budget, |_| {}),
+    },
+    Implementation {
+        name: "bigint compiled",
+        wide: true,
+        run: |program, input, budget| {
+            run::<FlatMemory<BigInt>>(program, input, budget, |computer| computer.compile())
+        },
+    },
+];
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Aspect {
+    State,
+    Output,
+    Memory,
+}
+
+/// The first way an implementation disagreed with the reference one.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Divergence {
+    pub program: Vec<isize>,
+    pub input: Vec<isize>,
+    pub reference: &'static str,
+    pub implementation: &'static str,
+    pub aspect: Aspect,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} disagrees with {} on {:?}: expected {}, found {}",
+            self.implementation, self.reference, self.aspect, self.expected, self.found
+        )?;
+        writeln!(f, "program: {}", format_program(&self.program))?;
+        write!(f, "input: {}", format_program(&self.input))
+    }
+}
+
+fn first_difference(expected: &Outcome, found: &Outcome) -> Option<(Aspect, String, String)> {
+    if expected.state != found.state {
+        return Some((
+            Aspect::State,
+            format!("{:?}", expected.state),
+            format!("{:?}", found.state),
+        ));
+    }
+
+    if expected.output != found.output {
+        return Some((
+            Aspect::Output,
+            expected.output.join(","),
+            found.output.join(","),
+        ));
+    }
+
+    let cell = |memory: &[(usize, String)], i: usize| match memory.get(i) {
+        Some((address, value)) => format!("{} at {}", value, address),
+        None => "nothing".to_string(),
+    };
+
+    (0..expected.memory.len().max(found.memory.len()))
+        .find(|&i| expected.memory.get(i) != found.memory.get(i))
+        .map(|i| {
+            (
+                Aspect::Memory,
+                cell(&expected.memory, i),
+                cell(&found.memory, i),
+            )
+        })
+}
+
+/// Runs the program on every implementation, each allowed `instructions`
+/// instructions, and compares halting state, output and memory. Wide word
+/// types are left out once the reference overflows.
+pub fn compare(program: &[isize], input: &[isize], instructions: u64) -> Option<Divergence> {
+    let reference = &IMPLEMENTATIONS[0];
+    let expected = (reference.run)(program, input, instructions);
+    let overflowed = matches!(expected.state, Err(IntcodeError::Overflow { .. }));
+
+    IMPLEMENTATIONS[1..]
+        .iter()
+        .filter(|implementation| !(overflowed && implementation.wide))
+        .find_map(|implementation| {
+            let found = (implementation.run)(program, input, instructions);
+
+            first_difference(&expected, &found).map(|(aspect, expected, found)| Divergence {
+                program: program.to_vec(),
+                input: input.to_vec(),
+                reference: reference.name,
+                implementation: implementation.name,
+                aspect,
+                expected,
+                found,
+            })
+        })
+}
+
+/// Compares `cases` random programs of up to `size` instructions, generated
+/// from `seed`, and stops at the first divergence.
+pub fn fuzz(
+    seed: u64,
+    cases: usize,
+    size: usize,
+    instructions: u64,
+) -> Result<(), Box<Divergence>> {
+    let mut rng = Rng::new(seed);
+
+    for _ in 0..cases {
+        let len = 1 + rng.below(size);
+        let program = random_program(&mut rng, len);
+        let input_len = rng.below(8);
+        let input = random_input(&mut rng, input_len);
+
+        if let Some(divergence) = compare(&program, &input, instructions) {
+            return Err(Box::new(divergence));
+        }
+    }
+
+    Ok(())
+}
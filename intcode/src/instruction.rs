@@ -0,0 +1,199 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub enum Opcode {
+    Add = 1,
+    Multiply = 2,
+    Input = 3,
+    Output = 4,
+    JumpIfTrue = 5,
+    JumpIfFalse = 6,
+    LessThan = 7,
+    Equals = 8,
+    AdjustRelativeBase = 9,
+    Halt = 99,
+}
+
+pub const OPCODES: [Opcode; 10] = [
+    Opcode::Add,
+    Opcode::Multiply,
+    Opcode::Input,
+    Opcode::Output,
+    Opcode::JumpIfTrue,
+    Opcode::JumpIfFalse,
+    Opcode::LessThan,
+    Opcode::Equals,
+    Opcode::AdjustRelativeBase,
+    Opcode::Halt,
+];
+
+impl Opcode {
+    pub fn from_code(code: isize) -> Option<Opcode> {
+        OPCODES
+            .iter()
+            .cloned()
+            .find(|&opcode| opcode as isize == code)
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Opcode> {
+        OPCODES
+            .iter()
+            .cloned()
+            .find(|opcode| opcode.mnemonic() == mnemonic)
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Add => "add",
+            Opcode::Multiply => "mul",
+            Opcode::Input => "in",
+            Opcode::Output => "out",
+            Opcode::JumpIfTrue => "jt",
+            Opcode::JumpIfFalse => "jf",
+            Opcode::LessThan => "lt",
+            Opcode::Equals => "eq",
+            Opcode::AdjustRelativeBase => "arb",
+            Opcode::Halt => "hlt",
+        }
+    }
+
+    pub fn number_of_params(self) -> usize {
+        match self {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => 3,
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => 2,
+            Opcode::Input | Opcode::Output | Opcode::AdjustRelativeBase => 1,
+            Opcode::Halt => 0,
+        }
+    }
+
+    /// Whether the last parameter is an address the instruction writes to.
+    pub fn writes_memory(self) -> bool {
+        matches!(
+            self,
+            Opcode::Add | Opcode::Multiply | Opcode::Input | Opcode::LessThan | Opcode::Equals
+        )
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub enum Mode {
+    Position = 0,
+    Immediate = 1,
+    Relative = 2,
+}
+
+impl Mode {
+    pub fn from_code(code: isize) -> Option<Mode> {
+        match code {
+            0 => Some(Mode::Position),
+            1 => Some(Mode::Immediate),
+            2 => Some(Mode::Relative),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub struct Parameter {
+    pub mode: Mode,
+    pub value: isize,
+}
+
+impl Display for Parameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            Mode::Position => write!(f, "[{}]", self.value),
+            Mode::Immediate => write!(f, "#{}", self.value),
+            Mode::Relative if self.value < 0 => write!(f, "rel{}", self.value),
+            Mode::Relative => write!(f, "rel+{}", self.value),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Clone, Debug)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub params: Vec<Parameter>,
+}
+
+impl Instruction {
+    /// Decodes the instruction at the start of `words`.
+    ///
+    /// Only canonical encodings are accepted: the word must re-encode to exactly
+    /// the same value, so stray mode digits or immediate-mode outputs are rejected.
+    pub fn decode(words: &[isize]) -> Option<Instruction> {
+        let instruction = *words.first()?;
+
+        if instruction < 0 {
+            return None;
+        }
+
+        let opcode = Opcode::from_code(instruction % 100)?;
+        let number_of_params = opcode.number_of_params();
+
+        if words.len() <= number_of_params {
+            return None;
+        }
+
+        let mut modes_num = instruction / 100;
+        let mut params = Vec::new();
+
+        for &value in words.iter().skip(1).take(number_of_params) {
+            let mode = Mode::from_code(modes_num % 10)?;
+            modes_num /= 10;
+
+            params.push(Parameter { mode, value });
+        }
+
+        if modes_num != 0 {
+            return None;
+        }
+
+        let instruction = Instruction { opcode, params };
+
+        if instruction.is_valid() {
+            Some(instruction)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.params.len() == self.opcode.number_of_params()
+            && !(self.opcode.writes_memory()
+                && self.params.last().map(|param| param.mode) == Some(Mode::Immediate))
+    }
+
+    pub fn encode(&self) -> Vec<isize> {
+        let mut instruction = self.opcode as isize;
+        let mut factor = 100;
+
+        for param in self.params.iter() {
+            instruction += param.mode as isize * factor;
+            factor *= 10;
+        }
+
+        let mut words = vec![instruction];
+        words.extend(self.params.iter().map(|param| param.value));
+
+        words
+    }
+
+    /// How many memory words the instruction takes up, its own included.
+    pub fn word_count(&self) -> usize {
+        self.params.len() + 1
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.opcode.mnemonic())?;
+
+        for (i, param) in self.params.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { " " } else { ", " }, param)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use crate::word::Word;
+use std::collections::VecDeque;
+use std::io::{BufRead, Write};
+use std::sync::mpsc::{Receiver, Sender};
+
+/// Where a computer gets its input from and sends its output to.
+pub trait IntcodeIo<W = isize> {
+    /// Next input value, or `None` if there is none yet and the computer
+    /// should pause.
+    fn read(&mut self) -> Option<W>;
+
+    fn write(&mut self, value: W);
+}
+
+impl<W, T: IntcodeIo<W> + ?Sized> IntcodeIo<W> for &mut T {
+    fn read(&mut self) -> Option<W> {
+        (**self).read()
+    }
+
+    fn write(&mut self, value: W) {
+        (**self).write(value)
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct QueueIo<W = isize> {
+    pub input: VecDeque<W>,
+    pub output: VecDeque<W>,
+}
+
+impl<W> QueueIo<W> {
+    pub fn new() -> QueueIo<W> {
+        QueueIo {
+            input: VecDeque::new(),
+            output: VecDeque::new(),
+        }
+    }
+}
+
+impl<W> IntcodeIo<W> for QueueIo<W> {
+    fn read(&mut self) -> Option<W> {
+        self.input.pop_front()
+    }
+
+    fn write(&mut self, value: W) {
+        self.output.push_back(value);
+    }
+}
+
+pub struct FnIo<R, W> {
+    read: R,
+    write: W,
+}
+
+impl<R, W> FnIo<R, W> {
+    pub fn new(read: R, write: W) -> FnIo<R, W> {
+        FnIo { read, write }
+    }
+}
+
+impl<T, R: FnMut() -> Option<T>, W: FnMut(T)> IntcodeIo<T> for FnIo<R, W> {
+    fn read(&mut self) -> Option<T> {
+        (self.read)()
+    }
+
+    fn write(&mut self, value: T) {
+        (self.write)(value)
+    }
+}
+
+/// Never blocks the thread: an empty or closed channel makes the computer
+/// pause, and output sent after the receiver is gone is dropped.
+pub struct ChannelIo<W = isize> {
+    receiver: Receiver<W>,
+    sender: Sender<W>,
+}
+
+impl<W> ChannelIo<W> {
+    pub fn new(receiver: Receiver<W>, sender: Sender<W>) -> ChannelIo<W> {
+        ChannelIo { receiver, sender }
+    }
+}
+
+impl<W> IntcodeIo<W> for ChannelIo<W> {
+    fn read(&mut self) -> Option<W> {
+        self.receiver.try_recv().ok()
+    }
+
+    fn write(&mut self, value: W) {
+        let _ = self.sender.send(value);
+    }
+}
+
+/// Feeds text from a reader byte by byte and prints output as text. Values
+/// outside the ASCII range are printed as numbers on their own line.
+pub struct AsciiIo<R, W> {
+    reader: R,
+    writer: W,
+}
+
+impl<R: BufRead, W: Write> AsciiIo<R, W> {
+    pub fn new(reader: R, writer: W) -> AsciiIo<R, W> {
+        AsciiIo { reader, writer }
+    }
+}
+
+impl<T: Word, R: BufRead, W: Write> IntcodeIo<T> for AsciiIo<R, W> {
+    fn read(&mut self) -> Option<T> {
+        let byte = *self.reader.fill_buf().ok()?.first()?;
+        self.reader.consume(1);
+
+        Some(T::from_isize(byte as isize))
+    }
+
+    fn write(&mut self, value: T) {
+        match value.to_isize() {
+            Some(byte) if (0..128).contains(&byte) => {
+                self.writer.write_all(&[byte as u8]).unwrap();
+
+                if byte == '\n' as isize {
+                    self.writer.flush().unwrap();
+                }
+            }
+            _ => {
+                writeln!(self.writer, "{}", value).unwrap();
+                self.writer.flush().unwrap();
+            }
+        }
+    }
+}
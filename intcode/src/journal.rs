@@ -0,0 +1,65 @@
+use std::collections::VecDeque;
+
+/// What an executed instruction changed, enough to undo it.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub(crate) struct Change<W> {
+    pub(crate) pointer: isize,
+    pub(crate) relative_base: isize,
+    pub(crate) overwritten: Option<(isize, W)>,
+    pub(crate) input: Option<W>,
+    pub(crate) output: bool,
+}
+
+impl<W> Change<W> {
+    pub(crate) fn is_io(&self) -> bool {
+        self.input.is_some() || self.output
+    }
+}
+
+/// The most recent changes, oldest dropped first once `capacity` is reached.
+#[derive(Clone, Debug)]
+pub(crate) struct Journal<W> {
+    capacity: usize,
+    changes: VecDeque<Change<W>>,
+}
+
+impl<W> Journal<W> {
+    pub(crate) fn new(capacity: usize) -> Journal<W> {
+        Journal {
+            capacity,
+            changes: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+
+        while self.changes.len() > capacity {
+            self.changes.pop_front();
+        }
+    }
+
+    pub(crate) fn record(&mut self, change: Change<W>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.changes.len() == self.capacity {
+            self.changes.pop_front();
+        }
+
+        self.changes.push_back(change);
+    }
+
+    pub(crate) fn pop(&mut self) -> Option<Change<W>> {
+        self.changes.pop_back()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.changes.clear();
+    }
+}
@@ -0,0 +1,36 @@
+mod assembler;
+#[cfg(feature = "async")]
+mod asynchronous;
+mod budget;
+mod cfg;
+mod compiler;
+mod computer;
+mod debugger;
+mod disassembler;
+mod error;
+mod fuzz;
+mod instruction;
+mod io;
+mod journal;
+mod memory;
+mod modification;
+mod snapshot;
+mod tracer;
+mod word;
+
+pub use assembler::{assemble, assemble_lines, assemble_listing};
+pub use budget::{Budget, Limit};
+pub use cfg::{BasicBlock, ControlFlowGraph, Exit};
+pub use computer::{format_program, parse_program, Computer, State};
+pub use debugger::{Debugger, Stop};
+pub use disassembler::{disassemble, listing, Entry, Line};
+pub use error::{AssemblyError, IntcodeError, ProgramError, SnapshotError};
+pub use fuzz::{compare, fuzz, random_input, random_program, Aspect, Divergence, Rng};
+pub use instruction::{Instruction, Mode, Opcode, Parameter, OPCODES};
+pub use io::{AsciiIo, ChannelIo, FnIo, IntcodeIo, QueueIo};
+pub use memory::{FlatMemory, Memory, SparseMemory};
+pub use modification::{ModificationReport, SelfModification};
+pub use tracer::{Profiler, TraceEvent, TraceLog, Tracer};
+pub use word::Word;
+
+pub use num_bigint::BigInt;
@@ -0,0 +1,95 @@
+use crate::word::Word;
+use std::collections::HashMap;
+
+pub trait Memory: Clone {
+    type Word: Word;
+
+    /// Programs can't write to this address or any above it. A budget can set
+    /// a lower limit.
+    const LIMIT: usize = usize::MAX;
+
+    fn from_program(program: &[Self::Word]) -> Self;
+
+    fn read(&self, address: usize) -> Self::Word;
+
+    fn write(&mut self, address: usize, value: Self::Word);
+
+    /// Nonzero cells in address order.
+    fn cells(&self) -> Vec<(usize, Self::Word)>;
+}
+
+/// Contiguous memory that grows to fit the highest written address, so
+/// programs may only write below `LIMIT` rather than allocate a cell for every
+/// address up to a huge one. Use `SparseMemory` for programs that need more.
+#[derive(Clone, Default, Debug)]
+pub struct FlatMemory<W: Word = isize> {
+    cells: Vec<W>,
+}
+
+impl<W: Word> Memory for FlatMemory<W> {
+    type Word = W;
+
+    const LIMIT: usize = 1 << 24;
+
+    fn from_program(program: &[W]) -> FlatMemory<W> {
+        FlatMemory {
+            cells: program.to_vec(),
+        }
+    }
+
+    fn read(&self, address: usize) -> W {
+        self.cells.get(address).cloned().unwrap_or_default()
+    }
+
+    fn write(&mut self, address: usize, value: W) {
+        if address >= self.cells.len() {
+            self.cells.resize(address + 1, W::default());
+        }
+
+        self.cells[address] = value;
+    }
+
+    fn cells(&self) -> Vec<(usize, W)> {
+        self.cells
+            .iter()
+            .cloned()
+            .enumerate()
+            .filter(|(_, value)| *value != W::default())
+            .collect()
+    }
+}
+
+/// Memory that only stores touched cells, for programs writing to huge addresses.
+#[derive(Clone, Default, Debug)]
+pub struct SparseMemory<W: Word = isize> {
+    cells: HashMap<usize, W>,
+}
+
+impl<W: Word> Memory for SparseMemory<W> {
+    type Word = W;
+
+    fn from_program(program: &[W]) -> SparseMemory<W> {
+        SparseMemory {
+            cells: program.iter().cloned().enumerate().collect(),
+        }
+    }
+
+    fn read(&self, address: usize) -> W {
+        self.cells.get(&address).cloned().unwrap_or_default()
+    }
+
+    fn write(&mut self, address: usize, value: W) {
+        self.cells.insert(address, value);
+    }
+
+    fn cells(&self) -> Vec<(usize, W)> {
+        let mut cells: Vec<(usize, W)> = self
+            .cells
+            .iter()
+            .map(|(&address, value)| (address, value.clone()))
+            .filter(|(_, value)| *value != W::default())
+            .collect();
+        cells.sort_unstable_by_key(|&(address, _)| address);
+        cells
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// A write to an address that has already been executed as part of an
+/// instruction.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct SelfModification<W = isize> {
+    /// Address of the instruction that did the write.
+    pub pointer: isize,
+    pub address: usize,
+    pub old: W,
+    pub new: W,
+}
+
+#[derive(Eq, PartialEq, Clone, Default, Debug)]
+struct Modified {
+    writes: u64,
+    changes: u64,
+    writers: BTreeSet<isize>,
+}
+
+/// Which addresses have been executed and which of those were written to
+/// afterwards. Addresses that were executed and never written stay the same
+/// for the whole run, so they are safe to cache or disassemble.
+#[derive(Eq, PartialEq, Clone, Default, Debug)]
+pub struct ModificationReport {
+    executed: Vec<bool>,
+    executed_count: usize,
+    modified: BTreeMap<usize, Modified>,
+}
+
+impl ModificationReport {
+    pub fn is_executed(&self, address: usize) -> bool {
+        self.executed.get(address).cloned().unwrap_or(false)
+    }
+
+    pub fn executed_count(&self) -> usize {
+        self.executed_count
+    }
+
+    pub fn is_modified(&self, address: usize) -> bool {
+        self.modified.contains_key(&address)
+    }
+
+    /// Executed and never written to since.
+    pub fn is_stable(&self, address: usize) -> bool {
+        self.is_executed(address) && !self.is_modified(address)
+    }
+
+    /// Executed addresses that were written to, in order.
+    pub fn modified_addresses(&self) -> Vec<usize> {
+        self.modified.keys().cloned().collect()
+    }
+
+    /// Writes to `address` after it was executed, including ones that stored
+    /// the value it already held.
+    pub fn writes_to(&self, address: usize) -> u64 {
+        self.modified
+            .get(&address)
+            .map_or(0, |modified| modified.writes)
+    }
+
+    /// Writes to `address` after it was executed that changed its value.
+    pub fn changes_to(&self, address: usize) -> u64 {
+        self.modified
+            .get(&address)
+            .map_or(0, |modified| modified.changes)
+    }
+
+    /// Instructions that wrote to `address` after it was executed.
+    pub fn writers_of(&self, address: usize) -> Vec<isize> {
+        self.modified.get(&address).map_or(Vec::new(), |modified| {
+            modified.writers.iter().cloned().collect()
+        })
+    }
+
+    pub(crate) fn execute(&mut self, pointer: usize, len: usize) {
+        if self.executed.len() < pointer + len {
+            self.executed.resize(pointer + len, false);
+        }
+
+        for executed in &mut self.executed[pointer..pointer + len] {
+            if !*executed {
+                *executed = true;
+                self.executed_count += 1;
+            }
+        }
+    }
+
+    pub(crate) fn write(&mut self, pointer: isize, address: usize, changed: bool) {
+        let modified = self.modified.entry(address).or_default();
+
+        modified.writes += 1;
+        modified.writers.insert(pointer);
+
+        if changed {
+            modified.changes += 1;
+        }
+    }
+}
+
+impl fmt::Display for ModificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} addresses executed, {} of them written to afterwards",
+            self.executed_count,
+            self.modified.len()
+        )?;
+
+        for (address, modified) in &self.modified {
+            let writers: Vec<String> = modified
+                .writers
+                .iter()
+                .map(|writer| format!("{:04}", writer))
+                .collect();
+
+            writeln!(
+                f,
+                "{:04} {:>8} writes {:>8} changes  by {}",
+                address,
+                modified.writes,
+                modified.changes,
+                writers.join(", ")
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) type ModificationHook<W> = Arc<Mutex<dyn FnMut(&SelfModification<W>) + Send>>;
@@ -0,0 +1,154 @@
+use crate::computer::{format_program, Computer};
+use crate::error::SnapshotError;
+use crate::memory::Memory;
+use crate::word::Word;
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+
+const HEADER: &str = "intcode snapshot v";
+const VERSION: usize = 1;
+
+/// Groups nonzero cells into runs of consecutive addresses, one line each.
+fn memory_lines<M: Memory>(name: &str, memory: &M) -> Vec<String> {
+    let mut runs: Vec<(usize, Vec<M::Word>)> = Vec::new();
+
+    for (address, value) in memory.cells() {
+        match runs.last_mut() {
+            Some((start, words)) if *start + words.len() == address => words.push(value),
+            _ => runs.push((address, vec![value])),
+        }
+    }
+
+    runs.iter()
+        .map(|(start, words)| format!("{} {} {}", name, start, format_program(words)))
+        .collect()
+}
+
+fn parse_words<W: Word>(text: &str) -> Option<Vec<W>> {
+    if text.is_empty() {
+        return Some(Vec::new());
+    }
+
+    text.split(',')
+        .map(|word| word.trim().parse().ok())
+        .collect()
+}
+
+fn parse_run<W: Word>(text: &str) -> Option<(usize, Vec<W>)> {
+    let mut parts = text.splitn(2, ' ');
+    let start = parts.next()?.parse().ok()?;
+    let words = parse_words(parts.next()?)?;
+
+    Some((start, words))
+}
+
+impl<M: Memory> Computer<M> {
+    /// Text snapshot of the whole machine: registers, I/O queues, current
+    /// memory and the original program, so `reset` still works after restoring.
+    pub fn snapshot(&self) -> String {
+        let mut lines = vec![
+            format!("{}{}", HEADER, VERSION),
+            format!("pointer {}", self.pointer()),
+            format!("relative_base {}", self.relative_base()),
+            format!(
+                "input {}",
+                format_program(&self.pending_input().iter().cloned().collect::<Vec<_>>())
+            ),
+            format!(
+                "output {}",
+                format_program(&self.pending_output().iter().cloned().collect::<Vec<_>>())
+            ),
+        ];
+
+        lines.extend(memory_lines("program", self.initial_memory()));
+        lines.extend(memory_lines("memory", self.memory()));
+
+        lines.iter().map(|line| format!("{}\n", line)).collect()
+    }
+
+    pub fn from_snapshot(snapshot: &str) -> Result<Computer<M>, SnapshotError> {
+        let mut lines = snapshot.lines().enumerate();
+
+        match lines.next() {
+            Some((_, header)) if header.trim() == format!("{}{}", HEADER, VERSION) => {}
+            Some((_, header)) if header.starts_with(HEADER) => {
+                return Err(SnapshotError::UnsupportedVersion(header.to_string()))
+            }
+            header => {
+                return Err(SnapshotError::Malformed {
+                    line: 1,
+                    text: header.map_or("", |(_, text)| text).to_string(),
+                })
+            }
+        }
+
+        let mut program = M::from_program(&[]);
+        let mut memory = M::from_program(&[]);
+        let mut pointer = 0;
+        let mut relative_base = 0;
+        let mut input = VecDeque::new();
+        let mut output = VecDeque::new();
+
+        for (i, text) in lines {
+            let malformed = || SnapshotError::Malformed {
+                line: i + 1,
+                text: text.to_string(),
+            };
+
+            let text = text.trim();
+
+            if text.is_empty() {
+                continue;
+            }
+
+            let (key, value) = match text.find(' ') {
+                Some(split) => (&text[..split], text[split + 1..].trim()),
+                None => (text, ""),
+            };
+
+            match key {
+                "pointer" => pointer = value.parse().map_err(|_| malformed())?,
+                "relative_base" => relative_base = value.parse().map_err(|_| malformed())?,
+                "input" => input = parse_words(value).ok_or_else(malformed)?.into(),
+                "output" => output = parse_words(value).ok_or_else(malformed)?.into(),
+                "program" | "memory" => {
+                    let (start, words) = parse_run(value).ok_or_else(malformed)?;
+
+                    match start.checked_add(words.len()) {
+                        Some(end) if end <= M::LIMIT => {}
+                        _ => return Err(malformed()),
+                    }
+
+                    let cells = if key == "program" {
+                        &mut program
+                    } else {
+                        &mut memory
+                    };
+
+                    for (offset, word) in words.into_iter().enumerate() {
+                        cells.write(start + offset, word);
+                    }
+                }
+                _ => return Err(malformed()),
+            }
+        }
+
+        Ok(Computer::from_state(
+            program,
+            memory,
+            pointer,
+            relative_base,
+            input,
+            output,
+        ))
+    }
+
+    pub fn save_snapshot<P: AsRef<Path>>(&self, path: P) -> Result<(), SnapshotError> {
+        Ok(fs::write(path, self.snapshot())?)
+    }
+
+    pub fn restore_snapshot<P: AsRef<Path>>(path: P) -> Result<Computer<M>, SnapshotError> {
+        Computer::from_snapshot(&fs::read_to_string(path)?)
+    }
+}
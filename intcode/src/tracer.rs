@@ -0,0 +1,198 @@
+use crate::instruction::{Instruction, Opcode};
+use crate::word::Word;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io;
+use std::io::Write;
+
+/// One executed instruction, with its words as they were before it ran.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct TraceEvent<W = isize> {
+    pub pointer: isize,
+    pub relative_base: isize,
+    pub words: Vec<W>,
+    /// The values the instruction read, each parameter resolved by its mode.
+    pub operands: Vec<W>,
+    /// The address written to, resolved by mode, and the value written.
+    pub write: Option<(isize, W)>,
+}
+
+impl<W: Word> TraceEvent<W> {
+    pub fn opcode(&self) -> Opcode {
+        Opcode::from_code(self.words[0].to_isize().unwrap() % 100).unwrap()
+    }
+
+    /// The instruction in assembly, if its words decode to one.
+    pub fn instruction(&self) -> Option<Instruction> {
+        let words: Option<Vec<isize>> = self.words.iter().map(Word::to_isize).collect();
+
+        Instruction::decode(&words?)
+    }
+}
+
+pub trait Tracer<W = isize> {
+    fn trace(&mut self, event: &TraceEvent<W>);
+}
+
+impl<W, F: FnMut(&TraceEvent<W>)> Tracer<W> for F {
+    fn trace(&mut self, event: &TraceEvent<W>) {
+        self(event)
+    }
+}
+
+/// Writes one tab-separated line per instruction: pointer, relative base,
+/// the decoded instruction, the values it read and the memory write, if any,
+/// as `address=value`.
+pub struct TraceLog<W: Write> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> TraceLog<W> {
+    pub fn new(writer: W) -> TraceLog<W> {
+        TraceLog {
+            writer,
+            error: None,
+        }
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Flushes the log and returns the first error hit while writing it.
+    pub fn finish(&mut self) -> io::Result<()> {
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => self.writer.flush(),
+        }
+    }
+}
+
+impl<T: Word, W: Write> Tracer<T> for TraceLog<W> {
+    fn trace(&mut self, event: &TraceEvent<T>) {
+        if self.error.is_some() {
+            return;
+        }
+
+        let join = |words: &[T]| {
+            words
+                .iter()
+                .map(|word| word.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let instruction = match event.instruction() {
+            Some(instruction) => instruction.to_string(),
+            None => join(&event.words),
+        };
+        let write = match &event.write {
+            Some((address, value)) => format!("{}={}", address, value),
+            None => String::new(),
+        };
+
+        if let Err(error) = writeln!(
+            self.writer,
+            "{}\t{}\t{}\t{}\t{}",
+            event.pointer,
+            event.relative_base,
+            instruction,
+            join(&event.operands),
+            write
+        ) {
+            self.error = Some(error);
+        }
+    }
+}
+
+/// Counts executed instructions per address and per opcode.
+#[derive(Clone, Default, Debug)]
+pub struct Profiler {
+    total: u64,
+    by_address: HashMap<isize, (u64, Vec<isize>)>,
+    by_opcode: HashMap<Opcode, u64>,
+}
+
+impl Profiler {
+    pub fn new() -> Profiler {
+        Profiler::default()
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn count_at(&self, address: isize) -> u64 {
+        self.by_address.get(&address).map_or(0, |(count, _)| *count)
+    }
+
+    pub fn count_of(&self, opcode: Opcode) -> u64 {
+        *self.by_opcode.get(&opcode).unwrap_or(&0)
+    }
+
+    /// Opcode counts followed by the `top` most executed addresses.
+    pub fn report(&self, top: usize) -> String {
+        let mut report = String::new();
+        let share = |count: u64| 100.0 * count as f64 / self.total.max(1) as f64;
+
+        writeln!(report, "{} instructions executed", self.total).unwrap();
+        writeln!(report, "\nby opcode:").unwrap();
+
+        let mut opcodes: Vec<_> = self.by_opcode.iter().collect();
+        opcodes.sort_by(|a, b| b.1.cmp(a.1).then((*a.0 as isize).cmp(&(*b.0 as isize))));
+
+        for (opcode, &count) in opcodes {
+            writeln!(
+                report,
+                "{:>5} {:>12} {:>6.2}%",
+                opcode.mnemonic(),
+                count,
+                share(count)
+            )
+            .unwrap();
+        }
+
+        writeln!(report, "\nby address:").unwrap();
+
+        let mut addresses: Vec<_> = self.by_address.iter().collect();
+        addresses.sort_by(|a, b| (b.1).0.cmp(&(a.1).0).then(a.0.cmp(b.0)));
+
+        for (address, (count, words)) in addresses.into_iter().take(top) {
+            let text = match Instruction::decode(words) {
+                Some(instruction) => instruction.to_string(),
+                None => format!("{:?}", words),
+            };
+
+            writeln!(
+                report,
+                "{:04} {:>12} {:>6.2}%  {}",
+                address,
+                count,
+                share(*count),
+                text
+            )
+            .unwrap();
+        }
+
+        report
+    }
+}
+
+/// Words that don't fit `isize` are recorded as `isize::MAX`.
+impl<W: Word> Tracer<W> for Profiler {
+    fn trace(&mut self, event: &TraceEvent<W>) {
+        self.total += 1;
+        *self.by_opcode.entry(event.opcode()).or_insert(0) += 1;
+
+        let entry = self.by_address.entry(event.pointer).or_insert_with(|| {
+            let words = event.words.iter();
+            (
+                0,
+                words
+                    .map(|word| word.to_isize().unwrap_or(isize::MAX))
+                    .collect(),
+            )
+        });
+        entry.0 += 1;
+    }
+}
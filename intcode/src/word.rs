@@ -0,0 +1,81 @@
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+/// A value stored in Intcode memory. `Default` is zero.
+pub trait Word: Clone + Eq + Ord + Default + Debug + Display + FromStr {
+    fn from_isize(value: isize) -> Self;
+
+    /// The value as an address, opcode or character, if it fits.
+    fn to_isize(&self) -> Option<isize>;
+
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+
+    fn wrapping_add(&self, other: &Self) -> Self;
+
+    fn wrapping_mul(&self, other: &Self) -> Self;
+}
+
+macro_rules! primitive_word {
+    ($($t:ty),*) => {
+        $(
+            impl Word for $t {
+                fn from_isize(value: isize) -> $t {
+                    value as $t
+                }
+
+                fn to_isize(&self) -> Option<isize> {
+                    ToPrimitive::to_isize(self)
+                }
+
+                fn checked_add(&self, other: &$t) -> Option<$t> {
+                    <$t>::checked_add(*self, *other)
+                }
+
+                fn checked_mul(&self, other: &$t) -> Option<$t> {
+                    <$t>::checked_mul(*self, *other)
+                }
+
+                fn wrapping_add(&self, other: &$t) -> $t {
+                    <$t>::wrapping_add(*self, *other)
+                }
+
+                fn wrapping_mul(&self, other: &$t) -> $t {
+                    <$t>::wrapping_mul(*self, *other)
+                }
+            }
+        )*
+    };
+}
+
+primitive_word!(isize, i64, i128);
+
+/// Arbitrary precision, so arithmetic never overflows.
+impl Word for BigInt {
+    fn from_isize(value: isize) -> BigInt {
+        BigInt::from(value)
+    }
+
+    fn to_isize(&self) -> Option<isize> {
+        ToPrimitive::to_isize(self)
+    }
+
+    fn checked_add(&self, other: &BigInt) -> Option<BigInt> {
+        Some(self + other)
+    }
+
+    fn checked_mul(&self, other: &BigInt) -> Option<BigInt> {
+        Some(self * other)
+    }
+
+    fn wrapping_add(&self, other: &BigInt) -> BigInt {
+        self + other
+    }
+
+    fn wrapping_mul(&self, other: &BigInt) -> BigInt {
+        self * other
+    }
+}
@@ -0,0 +1,110 @@
+use intcode::{assemble, listing, parse_program, random_program, AssemblyError, Rng};
+
+const PUZZLES: [&str; 6] = [
+    include_str!("../../day2_program_alarm/input.txt"),
+    include_str!("../../day5_chance_of_asteroids/input.txt"),
+    include_str!("../../day9_sensor_boost/input.txt"),
+    include_str!("../../day13_care_package/input.txt"),
+    include_str!("../../day17_set_and_forget/input.txt"),
+    include_str!("../../day25_cryostasis/input.txt"),
+];
+
+fn round_trips(program: &[isize]) {
+    assert_eq!(
+        assemble(&listing(program)).as_deref(),
+        Ok(program),
+        "{}",
+        listing(program)
+    );
+}
+
+#[test]
+fn listings_of_puzzle_inputs_assemble_back() {
+    for input in PUZZLES.iter() {
+        round_trips(&parse_program(input).unwrap());
+    }
+}
+
+#[test]
+fn listings_of_random_programs_assemble_back() {
+    let mut rng = Rng::new(2019);
+
+    for _ in 0..100 {
+        round_trips(&random_program(&mut rng, 32));
+    }
+}
+
+#[test]
+fn labels_resolve_to_addresses() {
+    let source = "\
+start: in [x]
+       jf [x], #done
+       out rel+x
+       jt #1, #start
+done:  hlt
+x:     .data 0, done+1";
+
+    assert_eq!(
+        assemble(source),
+        Ok(vec![3, 11, 1006, 11, 10, 204, 11, 1105, 1, 0, 99, 0, 11])
+    );
+}
+
+#[test]
+fn errors_name_the_line() {
+    let cases = [
+        (
+            "in [0]\nmov [1], [2]",
+            AssemblyError::UnknownMnemonic {
+                line: 2,
+                mnemonic: String::from("mov"),
+            },
+        ),
+        (
+            "add %1, [2], [3]",
+            AssemblyError::Syntax {
+                line: 1,
+                text: String::from("add %1, [2], [3]"),
+            },
+        ),
+        (
+            "out rel*3",
+            AssemblyError::Syntax {
+                line: 1,
+                text: String::from("out rel*3"),
+            },
+        ),
+        (
+            "out [1], [2]",
+            AssemblyError::WrongOperandCount {
+                line: 1,
+                mnemonic: String::from("out"),
+                expected: 1,
+                found: 2,
+            },
+        ),
+        (
+            "hlt\nadd #1, #2, #3",
+            AssemblyError::ImmediateOutput { line: 2 },
+        ),
+        (
+            "jt #1, #nowhere",
+            AssemblyError::UnknownLabel {
+                line: 1,
+                label: String::from("nowhere"),
+            },
+        ),
+        (
+            "a: hlt\na: hlt",
+            AssemblyError::DuplicateLabel {
+                line: 2,
+                label: String::from("a"),
+            },
+        ),
+    ];
+
+    for (source, error) in cases.iter() {
+        assert_eq!(assemble(source).as_ref(), Err(error), "{:?}", source);
+        assert_eq!(error.line(), source.lines().count());
+    }
+}
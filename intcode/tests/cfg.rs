@@ -0,0 +1,110 @@
+use intcode::{assemble, parse_program, ControlFlowGraph, Exit};
+
+/// Calls a function that returns straight away, outputs 1, then jumps through
+/// `target` unless `flag` is set.
+const CALL_AND_RETURN: &str = "\
+       arb #100
+       add #back, #0, rel+0
+       jt #1, #function
+back:  out #1
+       jf [flag], [target]
+end:   hlt
+function: jt #1, rel+0
+flag:   .data 0
+target: .data end";
+
+fn call_and_return() -> ControlFlowGraph {
+    ControlFlowGraph::build(&assemble(CALL_AND_RETURN).unwrap())
+}
+
+#[test]
+fn blocks_end_at_calls_returns_and_jumps() {
+    let cfg = call_and_return();
+    let exits: Vec<(usize, usize, Exit)> = cfg
+        .blocks()
+        .map(|block| (block.start, block.end(), block.exit))
+        .collect();
+
+    assert_eq!(
+        exits,
+        vec![
+            (
+                0,
+                9,
+                Exit::Call {
+                    target: 15,
+                    return_to: 9
+                }
+            ),
+            (
+                9,
+                14,
+                Exit::Indirect {
+                    fall_through: Some(14)
+                }
+            ),
+            (14, 15, Exit::Halt),
+            (15, 18, Exit::Return),
+        ]
+    );
+
+    assert_eq!(cfg.block(9).unwrap().instructions.len(), 2);
+    assert_eq!(cfg.block(18), None);
+}
+
+#[test]
+fn functions_and_indirect_jumps() {
+    let cfg = call_and_return();
+
+    assert_eq!(cfg.functions(), vec![0, 15]);
+    assert_eq!(cfg.indirect_jumps(), vec![11]);
+}
+
+#[test]
+fn dot_draws_every_block_and_edge() {
+    let dot = call_and_return().to_dot();
+
+    assert!(dot.starts_with("digraph intcode {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert_eq!(dot.matches(" [label=\"").count(), 4 + 3);
+
+    for edge in [
+        "b0 -> b15 [label=\"call\", style=bold];",
+        "b0 -> b9 [label=\"return\", style=dashed];",
+        "b9 -> b14 [label=\"not taken\"];",
+    ]
+    .iter()
+    {
+        assert!(dot.contains(edge), "{} is missing from\n{}", edge, dot);
+    }
+
+    assert!(dot.contains("indirect jump\\l\", color=red];"));
+    assert!(dot.contains("halt\\l\", peripheries=2];"));
+    assert!(dot.contains("0015: jt #1, rel+0\\lreturn\\l\"];"));
+}
+
+#[test]
+fn day9_program() {
+    let program = parse_program(include_str!("../../day9_sensor_boost/input.txt")).unwrap();
+    let cfg = ControlFlowGraph::build(&program);
+    let count = |matches: fn(&Exit) -> bool| cfg.blocks().filter(|b| matches(&b.exit)).count();
+
+    assert_eq!(cfg.blocks().count(), 24);
+    assert_eq!(count(|exit| *exit == Exit::Halt), 4);
+    assert_eq!(count(|exit| matches!(exit, Exit::Branch { .. })), 8);
+    assert_eq!(count(|exit| matches!(exit, Exit::Call { .. })), 3);
+    assert_eq!(count(|exit| *exit == Exit::Return), 2);
+
+    // The self-test, then the recursive function that computes the answer.
+    assert_eq!(cfg.functions(), vec![0, 920]);
+    assert!(cfg.indirect_jumps().is_empty());
+
+    let entry = cfg.block(0).unwrap();
+    assert_eq!(
+        entry.exit,
+        Exit::Branch {
+            taken: 53,
+            not_taken: 11
+        }
+    );
+}
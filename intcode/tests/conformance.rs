@@ -0,0 +1,268 @@
+use intcode::{
+    format_program, BigInt, Computer, FlatMemory, IntcodeError, Memory, ProgramError, SparseMemory,
+    Word,
+};
+
+#[derive(Eq, PartialEq, Debug)]
+struct Outcome {
+    result: Result<(), IntcodeError>,
+    outputs: Vec<String>,
+    memory: String,
+}
+
+fn run<M: Memory>(program: &str, input: &[isize], compiled: bool) -> Outcome {
+    let mut computer: Computer<M> = Computer::load(program).unwrap();
+    let len = program.split(',').count();
+
+    if compiled {
+        computer.compile();
+    }
+
+    for &value in input {
+        computer.provide_input(M::Word::from_isize(value));
+    }
+
+    let result = computer.run();
+    let outputs = computer
+        .read_outputs()
+        .iter()
+        .map(|value| value.to_string())
+        .collect();
+    let memory: Vec<M::Word> = (0..len)
+        .map(|address| computer.read_memory(address))
+        .collect();
+
+    Outcome {
+        result,
+        outputs,
+        memory: format_program(&memory),
+    }
+}
+
+type Variant = (&'static str, fn(&str, &[isize]) -> Outcome);
+
+const VARIANTS: [Variant; 11] = [
+    ("flat", |program, input| {
+        run::<FlatMemory>(program, input, false)
+    }),
+    ("flat compiled", |program, input| {
+        run::<FlatMemory>(program, input, true)
+    }),
+    ("sparse", |program, input| {
+        run::<SparseMemory>(program, input, false)
+    }),
+    ("sparse compiled", |program, input| {
+        run::<SparseMemory>(program, input, true)
+    }),
+    ("i64", |program, input| {
+        run::<FlatMemory<i64>>(program, input, false)
+    }),
+    ("i128", |program, input| {
+        run::<FlatMemory<i128>>(program, input, false)
+    }),
+    ("i128 compiled", |program, input| {
+        run::<FlatMemory<i128>>(program, input, true)
+    }),
+    ("sparse i128 compiled", |program, input| {
+        run::<SparseMemory<i128>>(program, input, true)
+    }),
+    ("bigint", |program, input| {
+        run::<FlatMemory<BigInt>>(program, input, false)
+    }),
+    ("bigint compiled", |program, input| {
+        run::<FlatMemory<BigInt>>(program, input, true)
+    }),
+    ("sparse bigint", |program, input| {
+        run::<SparseMemory<BigInt>>(program, input, false)
+    }),
+];
+
+/// Runs the program on every variant and checks they all agree with the
+/// first one, which is returned.
+fn run_everywhere(program: &str, input: &[isize]) -> Outcome {
+    let (reference_name, reference) = VARIANTS[0];
+    let expected = reference(program, input);
+
+    for &(name, variant) in VARIANTS[1..].iter() {
+        assert_eq!(
+            variant(program, input),
+            expected,
+            "{} disagrees with {} on {} with input {:?}",
+            name,
+            reference_name,
+            program,
+            input
+        );
+    }
+
+    expected
+}
+
+fn assert_memory(program: &str, memory: &str) {
+    let outcome = run_everywhere(program, &[]);
+
+    assert_eq!(outcome.result, Ok(()));
+    assert_eq!(outcome.memory, memory);
+}
+
+fn assert_outputs(program: &str, input: &[isize], outputs: &[&str]) {
+    let outcome = run_everywhere(program, input);
+
+    assert_eq!(outcome.result, Ok(()));
+    assert_eq!(outcome.outputs, outputs, "input {:?}", input);
+}
+
+#[test]
+fn day2_add_and_multiply() {
+    assert_memory(
+        "1,9,10,3,2,3,11,0,99,30,40,50",
+        "3500,9,10,70,2,3,11,0,99,30,40,50",
+    );
+    assert_memory("1,0,0,0,99", "2,0,0,0,99");
+    assert_memory("2,3,0,3,99", "2,3,0,6,99");
+    assert_memory("2,4,4,5,99,0", "2,4,4,5,99,9801");
+    assert_memory("1,1,1,4,99,5,6,0,99", "30,1,1,4,2,5,6,0,99");
+}
+
+#[test]
+fn day5_input_and_output() {
+    for &value in [0, 42, -7].iter() {
+        assert_outputs("3,0,4,0,99", &[value], &[&value.to_string()]);
+    }
+}
+
+#[test]
+fn day5_immediate_mode() {
+    assert_memory("1002,4,3,4,33", "1002,4,3,4,99");
+    assert_memory("1101,100,-1,4,0", "1101,100,-1,4,99");
+}
+
+#[test]
+fn day5_compare_to_8() {
+    let equal = ["3,9,8,9,10,9,4,9,99,-1,8", "3,3,1108,-1,8,3,4,3,99"];
+    let less = ["3,9,7,9,10,9,4,9,99,-1,8", "3,3,1107,-1,8,3,4,3,99"];
+
+    for &input in [-3, 7, 8, 9].iter() {
+        for program in equal.iter() {
+            let expected = if input == 8 { "1" } else { "0" };
+            assert_outputs(program, &[input], &[expected]);
+        }
+
+        for program in less.iter() {
+            let expected = if input < 8 { "1" } else { "0" };
+            assert_outputs(program, &[input], &[expected]);
+        }
+    }
+}
+
+#[test]
+fn day5_jumps() {
+    let programs = [
+        "3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9",
+        "3,3,1105,-1,9,1101,0,0,12,4,12,99,1",
+    ];
+
+    for &input in [0, 1, -5].iter() {
+        for program in programs.iter() {
+            let expected = if input == 0 { "0" } else { "1" };
+            assert_outputs(program, &[input], &[expected]);
+        }
+    }
+}
+
+#[test]
+fn day5_larger_example() {
+    let program = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,\
+                   1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,\
+                   999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+
+    assert_outputs(program, &[7], &["999"]);
+    assert_outputs(program, &[8], &["1000"]);
+    assert_outputs(program, &[9], &["1001"]);
+}
+
+#[test]
+fn day9_quine() {
+    let program = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+    let outputs: Vec<&str> = program.split(',').collect();
+
+    assert_outputs(program, &[], &outputs);
+}
+
+#[test]
+fn day9_large_numbers() {
+    let outcome = run_everywhere("1102,34915192,34915192,7,4,7,99,0", &[]);
+    assert_eq!(outcome.outputs[0].len(), 16);
+
+    assert_outputs("104,1125899906842624,99", &[], &["1125899906842624"]);
+}
+
+#[test]
+fn relative_mode_reads_and_writes() {
+    assert_outputs("109,7,203,0,204,0,99", &[5], &["5"]);
+    assert_outputs("109,10,21101,3,4,-1,204,-1,99", &[], &["7"]);
+    assert_outputs("109,3,209,-2,204,-4,99", &[], &["209"]);
+    assert_outputs("109,20,22201,-20,-19,-10,204,-10,99", &[], &["129"]);
+}
+
+#[test]
+fn faults_agree() {
+    let cases = [
+        ("1,0,0,0,98", &[][..]),
+        ("104,1,3,0,99", &[][..]),
+        ("1,-1,0,0,99", &[][..]),
+        ("109,-5,204,0,99", &[][..]),
+        ("30001,0,0,0,99", &[][..]),
+        ("11101,1,1,5,99", &[][..]),
+    ];
+
+    for &(program, input) in cases.iter() {
+        assert!(
+            run_everywhere(program, input).result.is_err(),
+            "{}",
+            program
+        );
+    }
+}
+
+#[test]
+fn malformed_programs_are_rejected() {
+    assert_eq!(
+        Computer::init("1,0,x,0,99").err(),
+        Some(ProgramError::InvalidWord {
+            position: 2,
+            word: String::from("x"),
+        })
+    );
+}
+
+#[test]
+fn oversized_instructions_are_reported_as_such() {
+    let mut computer: Computer<FlatMemory<i128>> =
+        Computer::load("104,1,100000000000000000000000,99").unwrap();
+
+    assert_eq!(
+        computer.run(),
+        Err(IntcodeError::InstructionOverflow { pointer: 2 })
+    );
+}
+
+#[test]
+fn flat_memory_refuses_huge_writes() {
+    let program = "1101,1,1,1000000000000,4,1000000000000,99";
+
+    for &compiled in [false, true].iter() {
+        let flat = run::<FlatMemory>(program, &[], compiled);
+        assert_eq!(
+            flat.result,
+            Err(IntcodeError::AddressOverflow {
+                pointer: 0,
+                instruction: 1101,
+            })
+        );
+
+        let sparse = run::<SparseMemory>(program, &[], compiled);
+        assert_eq!(sparse.result, Ok(()));
+        assert_eq!(sparse.outputs, vec!["2"]);
+    }
+}
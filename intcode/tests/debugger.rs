@@ -0,0 +1,173 @@
+use intcode::{Budget, Computer, Debugger, Limit, Opcode, Stop};
+
+/// Reads a number into address 20, adds it to address 21 (10 to begin with)
+/// and outputs the sum, then jumps over a halt to output twice the sum.
+const PROGRAM: &str = "3,20,1,20,21,21,4,21,1105,1,12,99,1002,21,2,22,4,22,99,0,0,10,0";
+
+fn debugger() -> Debugger {
+    let mut computer = Computer::init(PROGRAM).unwrap();
+    computer.enable_journal(100);
+
+    Debugger::new(computer)
+}
+
+#[test]
+fn resume_waits_for_input_and_runs_to_the_end() {
+    let mut debugger = debugger();
+
+    assert_eq!(debugger.resume(), Ok(Stop::AwaitingInput));
+    assert_eq!(debugger.computer().pointer(), 0);
+
+    debugger.computer_mut().provide_input(5);
+    assert_eq!(debugger.resume(), Ok(Stop::Finished));
+    assert_eq!(debugger.computer_mut().read_outputs(), vec![15, 30]);
+}
+
+#[test]
+fn step_runs_one_instruction() {
+    let mut debugger = debugger();
+    debugger.computer_mut().provide_input(5);
+
+    assert_eq!(debugger.step(), Ok(Stop::Stepped));
+    assert_eq!(debugger.step(), Ok(Stop::Stepped));
+    assert_eq!(debugger.step(), Ok(Stop::Output(15)));
+    assert_eq!(debugger.computer().pointer(), 8);
+}
+
+#[test]
+fn breakpoints_stop_before_the_instruction_runs() {
+    let mut debugger = debugger();
+    debugger.computer_mut().provide_input(5);
+    debugger.add_breakpoint(12);
+    debugger.add_breakpoint(11);
+
+    assert_eq!(debugger.resume(), Ok(Stop::Breakpoint(12)));
+    assert_eq!(debugger.computer().read_memory(22), 0);
+    assert_eq!(debugger.computer().pending_output().len(), 1);
+
+    assert_eq!(debugger.resume(), Ok(Stop::Finished));
+    assert_eq!(debugger.computer().read_memory(22), 30);
+
+    assert!(debugger.remove_breakpoint(11));
+    assert!(!debugger.remove_breakpoint(11));
+    assert_eq!(debugger.breakpoints(), vec![12]);
+}
+
+#[test]
+fn opcode_breakpoints_stop_on_any_matching_instruction() {
+    let mut debugger = debugger();
+    debugger.computer_mut().provide_input(5);
+    debugger.add_opcode_breakpoint(Opcode::Output);
+
+    assert_eq!(
+        debugger.resume(),
+        Ok(Stop::OpcodeBreakpoint(Opcode::Output))
+    );
+    assert_eq!(debugger.computer().pointer(), 6);
+    assert_eq!(
+        debugger.resume(),
+        Ok(Stop::OpcodeBreakpoint(Opcode::Output))
+    );
+    assert_eq!(debugger.computer().pointer(), 16);
+
+    assert!(debugger.remove_opcode_breakpoint(Opcode::Output));
+    assert_eq!(debugger.resume(), Ok(Stop::Finished));
+}
+
+#[test]
+fn watchpoints_report_changes_but_not_pokes() {
+    let mut debugger = debugger();
+    debugger.computer_mut().provide_input(5);
+    debugger.add_watchpoint(21);
+    debugger.add_watchpoint(22);
+
+    let stop = Stop::Watchpoint {
+        address: 21,
+        old: 10,
+        new: 15,
+    };
+    assert_eq!(debugger.resume(), Ok(stop));
+    assert_eq!(debugger.computer().pointer(), 6);
+
+    debugger.poke(22, 7);
+    let stop = Stop::Watchpoint {
+        address: 22,
+        old: 7,
+        new: 30,
+    };
+    assert_eq!(debugger.resume(), Ok(stop));
+    assert_eq!(debugger.watchpoints(), vec![21, 22]);
+}
+
+#[test]
+fn budget_exhaustion_hands_back_control() {
+    let mut debugger = debugger();
+    debugger.computer_mut().provide_input(5);
+    debugger.computer_mut().set_budget(Budget {
+        instructions: Some(2),
+        ..Budget::default()
+    });
+
+    assert_eq!(
+        debugger.resume(),
+        Ok(Stop::BudgetExhausted(Limit::Instructions))
+    );
+}
+
+#[test]
+fn step_back_refreshes_watchpoints() {
+    let mut debugger = debugger();
+    debugger.computer_mut().provide_input(5);
+    debugger.add_breakpoint(12);
+
+    assert_eq!(debugger.resume(), Ok(Stop::Breakpoint(12)));
+    debugger.add_watchpoint(21);
+
+    assert_eq!(debugger.step_back(10), 4);
+    assert_eq!(debugger.computer().pointer(), 0);
+    assert_eq!(debugger.computer().pending_input().len(), 1);
+    assert!(debugger.computer().pending_output().is_empty());
+
+    // The watchpoint now expects the 10 that was there before, so the first
+    // stop is the add writing 15 over it rather than the input next to it.
+    let stop = Stop::Watchpoint {
+        address: 21,
+        old: 10,
+        new: 15,
+    };
+    assert_eq!(debugger.resume(), Ok(stop));
+    assert_eq!(debugger.computer().pointer(), 6);
+}
+
+#[test]
+fn reset_keeps_breakpoints_and_refreshes_watchpoints() {
+    let mut debugger = debugger();
+    debugger.computer_mut().provide_input(5);
+    debugger.add_breakpoint(16);
+    debugger.add_watchpoint(22);
+
+    assert_eq!(
+        debugger.resume(),
+        Ok(Stop::Watchpoint {
+            address: 22,
+            old: 0,
+            new: 30
+        })
+    );
+    assert_eq!(debugger.computer().pointer(), 16);
+    assert_eq!(debugger.resume(), Ok(Stop::Finished));
+
+    debugger.reset();
+    assert_eq!(debugger.computer().pointer(), 0);
+    assert_eq!(debugger.breakpoints(), vec![16]);
+
+    debugger.computer_mut().provide_input(5);
+    assert_eq!(
+        debugger.resume(),
+        Ok(Stop::Watchpoint {
+            address: 22,
+            old: 0,
+            new: 30
+        })
+    );
+}
@@ -0,0 +1,30 @@
+use intcode::{compare, fuzz};
+
+#[test]
+fn implementations_agree_on_random_programs() {
+    for seed in 0..4 {
+        if let Err(divergence) = fuzz(seed, 250, 32, 10_000) {
+            panic!("seed {}: {}", seed, divergence);
+        }
+    }
+}
+
+#[test]
+fn self_modifying_program() {
+    // Rewrites the add at 4 into a multiply before it runs.
+    let program = [1101, 1, 1, 4, 1, 6, 7, 11, 104, 0, 99, 0];
+
+    assert_eq!(compare(&program, &[], 100), None);
+}
+
+#[test]
+fn runaway_program_stops_on_budget() {
+    assert_eq!(compare(&[1105, 1, 0], &[], 1000), None);
+}
+
+#[test]
+fn overflow_is_not_a_divergence() {
+    let program = [1102, 1 << 40, 1 << 40, 7, 4, 7, 99, 0];
+
+    assert_eq!(compare(&program, &[], 100), None);
+}
@@ -0,0 +1,74 @@
+use intcode::{AsciiIo, ChannelIo, Computer, FnIo, QueueIo, State};
+use std::collections::VecDeque;
+use std::sync::mpsc;
+
+/// Doubles every input, forever.
+const DOUBLER: &str = "3,100,1002,100,2,100,4,100,1105,1,0";
+
+/// Outputs 1000, then echoes every input, forever.
+const ECHO: &str = "104,1000,3,100,4,100,1105,1,2";
+
+#[test]
+fn queue_io_pauses_once_its_input_runs_out() {
+    let mut computer = Computer::init(DOUBLER).unwrap();
+    let mut io = QueueIo::new();
+    io.input.extend(vec![1, 2, 3]);
+
+    // Output already pending in the computer is handed over first.
+    computer.provide_input(5);
+    assert_eq!(computer.compute(), Ok(State::AwaitingInput));
+
+    assert_eq!(computer.compute_with_io(&mut io), Ok(State::AwaitingInput));
+    assert_eq!(io.output, VecDeque::from(vec![10, 2, 4, 6]));
+    assert!(io.input.is_empty());
+    assert_eq!(computer.pointer(), 0);
+
+    io.input.push_back(4);
+    assert_eq!(computer.compute_with_io(&mut io), Ok(State::AwaitingInput));
+    assert_eq!(io.output.back(), Some(&8));
+}
+
+#[test]
+fn fn_io_pauses_once_read_returns_none() {
+    let mut computer = Computer::init(DOUBLER).unwrap();
+    let mut input = vec![1, 2, 3].into_iter();
+    let mut output = Vec::new();
+
+    let io = FnIo::new(|| input.next(), |value| output.push(value));
+    assert_eq!(computer.compute_with_io(io), Ok(State::AwaitingInput));
+    assert_eq!(output, vec![2, 4, 6]);
+}
+
+#[test]
+fn channel_io_pauses_on_an_empty_or_closed_channel() {
+    let mut computer = Computer::init(DOUBLER).unwrap();
+    let (input, receiver) = mpsc::channel();
+    let (sender, output) = mpsc::channel();
+    let mut io = ChannelIo::new(receiver, sender);
+
+    input.send(1).unwrap();
+    input.send(2).unwrap();
+    assert_eq!(computer.compute_with_io(&mut io), Ok(State::AwaitingInput));
+    assert_eq!(output.try_iter().collect::<Vec<_>>(), vec![2, 4]);
+
+    input.send(3).unwrap();
+    drop(input);
+    assert_eq!(computer.compute_with_io(&mut io), Ok(State::AwaitingInput));
+    assert_eq!(output.try_iter().collect::<Vec<_>>(), vec![6]);
+
+    // Nobody is listening any more, so the output is dropped.
+    drop(output);
+    computer.provide_input(4);
+    assert_eq!(computer.compute_with_io(&mut io), Ok(State::AwaitingInput));
+}
+
+#[test]
+fn ascii_io_pauses_at_the_end_of_the_text() {
+    let mut computer = Computer::init(ECHO).unwrap();
+    let mut output = Vec::new();
+
+    let io = AsciiIo::new("hi\n".as_bytes(), &mut output);
+    assert_eq!(computer.compute_with_io(io), Ok(State::AwaitingInput));
+    assert_eq!(String::from_utf8(output).unwrap(), "1000\nhi\n");
+    assert_eq!(computer.pointer(), 2);
+}
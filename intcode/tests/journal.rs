@@ -0,0 +1,137 @@
+use intcode::{Computer, State};
+use std::collections::VecDeque;
+
+/// Moves the relative base, reads two inputs, adds them into address 30 and
+/// outputs the sum relative to the base, then reads a third input and halts.
+const PROGRAM: &str = "109,7,3,30,3,31,1,30,31,30,204,23,3,32,99";
+
+/// Everything a rewind has to put back.
+#[derive(Eq, PartialEq, Debug)]
+struct Machine {
+    pointer: isize,
+    relative_base: isize,
+    memory: Vec<isize>,
+    input: VecDeque<isize>,
+    output: VecDeque<isize>,
+}
+
+fn machine(computer: &Computer) -> Machine {
+    Machine {
+        pointer: computer.pointer(),
+        relative_base: computer.relative_base(),
+        memory: (0..40)
+            .map(|address| computer.read_memory(address))
+            .collect(),
+        input: computer.pending_input().clone(),
+        output: computer.pending_output().clone(),
+    }
+}
+
+fn computer(capacity: usize) -> Computer {
+    let mut computer = Computer::init(PROGRAM).unwrap();
+    computer.enable_journal(capacity);
+
+    for value in [4, 5, 6].iter() {
+        computer.provide_input(*value);
+    }
+
+    computer
+}
+
+/// Steps `computer` to the end, returning the machine before every step.
+fn run(computer: &mut Computer) -> Vec<Machine> {
+    let mut history = Vec::new();
+
+    loop {
+        history.push(machine(computer));
+
+        if computer.step().unwrap() == Some(State::Finished) {
+            return history;
+        }
+    }
+}
+
+#[test]
+fn rewinding_restores_every_earlier_state() {
+    let mut computer = computer(100);
+    let mut history = run(&mut computer);
+
+    // The halt isn't journaled, as it changes nothing.
+    history.pop();
+    assert_eq!(computer.journal_len(), history.len());
+
+    while let Some(earlier) = history.pop() {
+        assert_eq!(computer.rewind(1), 1);
+        assert_eq!(machine(&computer), earlier);
+    }
+
+    assert_eq!(computer.journal_len(), 0);
+    assert_eq!(computer.rewind(1), 0);
+    assert_eq!(computer.pointer(), 0);
+}
+
+#[test]
+fn rewinding_several_steps_at_once() {
+    let mut computer = computer(100);
+    let history = run(&mut computer);
+
+    assert_eq!(computer.rewind(4), 4);
+    assert_eq!(machine(&computer), history[2]);
+    assert_eq!(computer.pending_input(), &VecDeque::from(vec![5, 6]));
+
+    assert_eq!(computer.compute(), Ok(State::Finished));
+    assert_eq!(computer.read_outputs(), vec![9]);
+}
+
+#[test]
+fn rewinding_to_io_stops_before_the_last_io_instruction() {
+    let mut computer = computer(100);
+    let history = run(&mut computer);
+
+    assert_eq!(computer.rewind_to_io(), 1);
+    assert_eq!(machine(&computer), history[5]);
+
+    assert_eq!(computer.rewind_to_io(), 1);
+    assert_eq!(machine(&computer), history[4]);
+    assert!(computer.pending_output().is_empty());
+}
+
+#[test]
+fn read_output_stays_read() {
+    let mut computer = computer(100);
+    run(&mut computer);
+
+    assert_eq!(computer.read_outputs(), vec![9]);
+    assert_eq!(computer.rewind(2), 2);
+    assert!(computer.pending_output().is_empty());
+    assert_eq!(computer.pointer(), 10);
+}
+
+#[test]
+fn full_journals_forget_the_oldest_steps() {
+    let mut computer = computer(2);
+    let history = run(&mut computer);
+    let steps = history.len() - 1;
+
+    assert_eq!(computer.journal_len(), 2);
+    assert_eq!(computer.rewind(10), 2);
+    assert_eq!(machine(&computer), history[steps - 2]);
+
+    assert_eq!(computer.compute(), Ok(State::Finished));
+    computer.enable_journal(1);
+    assert_eq!(computer.journal_len(), 1);
+
+    computer.enable_journal(0);
+    assert_eq!(computer.journal_len(), 0);
+    assert_eq!(computer.rewind(1), 0);
+}
+
+#[test]
+fn reset_clears_the_journal() {
+    let mut computer = computer(100);
+    run(&mut computer);
+
+    computer.reset();
+    assert_eq!(computer.journal_len(), 0);
+    assert_eq!(computer.rewind(1), 0);
+}
@@ -0,0 +1,103 @@
+use intcode::{Computer, SelfModification};
+use std::sync::{Arc, Mutex};
+
+/// Outputs the operand of its first instruction, bumps that operand and loops
+/// until it reaches 3. Every pass also stores 104 over the opcode it started
+/// with, which changes nothing.
+const PROGRAM: &str = "104,1,1001,1,1,1,1007,1,3,20,1101,104,0,0,1005,20,0,99";
+
+fn tracked(compiled: bool) -> Computer {
+    let mut computer = Computer::init(PROGRAM).unwrap();
+    computer.track_self_modification();
+
+    if compiled {
+        computer.compile();
+    }
+
+    computer
+}
+
+#[test]
+fn report_counts_writes_to_executed_addresses() {
+    for &compiled in [false, true].iter() {
+        let mut computer = tracked(compiled);
+        computer.run().unwrap();
+        assert_eq!(computer.read_outputs(), vec![1, 2]);
+
+        let report = computer.modification_report().unwrap();
+        assert_eq!(report.executed_count(), 17);
+        assert_eq!(report.modified_addresses(), vec![0, 1]);
+
+        assert_eq!(report.writes_to(0), 2);
+        assert_eq!(report.changes_to(0), 0);
+        assert_eq!(report.writers_of(0), vec![10]);
+
+        assert_eq!(report.writes_to(1), 2);
+        assert_eq!(report.changes_to(1), 2);
+        assert_eq!(report.writers_of(1), vec![2]);
+
+        assert!(report.is_stable(2));
+        assert!(!report.is_stable(1));
+        assert!(!report.is_executed(20));
+        assert!(!report.is_modified(20));
+    }
+}
+
+#[test]
+fn hook_sees_every_write_to_executed_addresses() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let mut computer = Computer::init(PROGRAM).unwrap();
+    let log = seen.clone();
+    computer.set_self_modification_hook(move |modification| {
+        log.lock().unwrap().push(modification.clone());
+    });
+
+    computer.run().unwrap();
+
+    let modification = |pointer, address, old, new| SelfModification {
+        pointer,
+        address,
+        old,
+        new,
+    };
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![
+            modification(2, 1, 1, 2),
+            modification(10, 0, 104, 104),
+            modification(2, 1, 2, 3),
+            modification(10, 0, 104, 104),
+        ]
+    );
+
+    computer.clear_self_modification_hook();
+    computer.reset();
+    computer.run().unwrap();
+    assert_eq!(seen.lock().unwrap().len(), 4);
+}
+
+#[test]
+fn reset_starts_a_new_report() {
+    let mut computer = tracked(false);
+    computer.run().unwrap();
+
+    computer.reset();
+    assert_eq!(computer.modification_report().unwrap().executed_count(), 0);
+
+    let report = computer.take_modification_report().unwrap();
+    assert!(report.modified_addresses().is_empty());
+    assert!(computer.modification_report().is_none());
+}
+
+#[test]
+fn report_display_lists_modified_addresses() {
+    let mut computer = tracked(false);
+    computer.run().unwrap();
+
+    assert_eq!(
+        computer.modification_report().unwrap().to_string(),
+        "17 addresses executed, 2 of them written to afterwards\n\
+         0000        2 writes        0 changes  by 0010\n\
+         0001        2 writes        2 changes  by 0002\n"
+    );
+}
@@ -0,0 +1,105 @@
+use intcode::{Computer, FlatMemory, Memory, SnapshotError, SparseMemory, State};
+use std::env;
+use std::fs;
+
+/// Reads two inputs, adds them into address 30 and outputs it, moving the
+/// relative base on the way; the third input waits in the queue.
+const PROGRAM: &str = "109,7,3,30,3,31,1,30,31,30,204,23,3,32,99";
+
+fn paused() -> Computer {
+    let mut computer = Computer::init(PROGRAM).unwrap();
+
+    for value in [4, 5, 6].iter() {
+        computer.provide_input(*value);
+    }
+
+    for _ in 0..5 {
+        computer.step().unwrap();
+    }
+
+    computer.write_memory(1000, 42);
+    computer
+}
+
+fn assert_same<M: Memory<Word = isize>>(restored: &Computer<M>, original: &Computer) {
+    assert_eq!(restored.pointer(), original.pointer());
+    assert_eq!(restored.relative_base(), original.relative_base());
+    assert_eq!(restored.pending_input(), original.pending_input());
+    assert_eq!(restored.pending_output(), original.pending_output());
+
+    for address in 0..1010 {
+        assert_eq!(
+            restored.read_memory(address),
+            original.read_memory(address),
+            "address {}",
+            address
+        );
+    }
+}
+
+#[test]
+fn snapshot_round_trips() {
+    let original = paused();
+    assert_eq!(original.pointer(), 12);
+    assert_eq!(original.relative_base(), 7);
+    assert_eq!(original.pending_input().len(), 1);
+    assert_eq!(original.pending_output().len(), 1);
+
+    let restored: Computer = Computer::from_snapshot(&original.snapshot()).unwrap();
+    assert_same(&restored, &original);
+
+    let sparse: Computer<SparseMemory> = Computer::from_snapshot(&original.snapshot()).unwrap();
+    assert_same(&sparse, &original);
+}
+
+#[test]
+fn restored_machine_runs_on_and_resets() {
+    let mut original = paused();
+    let mut restored: Computer = Computer::from_snapshot(&original.snapshot()).unwrap();
+
+    assert_eq!(original.compute(), Ok(State::Finished));
+    assert_eq!(restored.compute(), Ok(State::Finished));
+    assert_eq!(restored.read_outputs(), original.read_outputs());
+
+    restored.reset();
+    assert_eq!(restored.pointer(), 0);
+    assert_eq!(restored.read_memory(30), 0);
+    assert_eq!(restored.read_memory(1000), 0);
+    assert_eq!(restored.read_memory(0), 109);
+}
+
+#[test]
+fn snapshot_files_round_trip() {
+    let original = paused();
+    let path = env::temp_dir().join(format!("intcode-snapshot-{}.txt", std::process::id()));
+
+    original.save_snapshot(&path).unwrap();
+    let restored: Result<Computer, _> = Computer::restore_snapshot(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_same(&restored.unwrap(), &original);
+}
+
+#[test]
+fn bad_snapshots_are_rejected() {
+    let snapshot = paused().snapshot();
+
+    match Computer::<SparseMemory>::from_snapshot(&snapshot.replace(" v1", " v2")) {
+        Err(SnapshotError::UnsupportedVersion(_)) => {}
+        other => panic!("expected an unsupported version, got {:?}", other.err()),
+    }
+
+    match Computer::<SparseMemory>::from_snapshot(&snapshot.replace("pointer 12", "pointer x")) {
+        Err(SnapshotError::Malformed { line: 2, .. }) => {}
+        other => panic!("expected a malformed line, got {:?}", other.err()),
+    }
+
+    let huge = format!("{}memory 1000000000000 1\n", snapshot);
+
+    match Computer::<FlatMemory>::from_snapshot(&huge) {
+        Err(SnapshotError::Malformed { .. }) => {}
+        other => panic!("expected a malformed line, got {:?}", other.err()),
+    }
+
+    assert!(Computer::<SparseMemory>::from_snapshot(&huge).is_ok());
+}
@@ -0,0 +1,21 @@
+use intcode::{Computer, TraceLog};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn log_shows_resolved_operands_and_writes() {
+    let mut computer = Computer::init("109,20,22201,0,1,2,204,2,99").unwrap();
+    let log = Arc::new(Mutex::new(TraceLog::new(Vec::new())));
+    computer.set_tracer(log.clone());
+    computer.write_memory(20, 2);
+    computer.write_memory(21, 3);
+
+    computer.run().unwrap();
+
+    let log = log.lock().unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(log.get_ref()),
+        "0\t20\tarb #20\t20\t\n\
+         2\t20\tadd rel+0, rel+1, rel+2\t2,3\t22=5\n\
+         6\t20\tout rel+2\t5\t\n"
+    );
+}
@@ -0,0 +1,122 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// How many pixels high every letter is.
+pub const HEIGHT: usize = 6;
+
+/// The letters of the Advent of Code font, lit pixels as `#`.
+const GLYPHS: [(char, [&str; HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[derive(Debug, PartialEq)]
+pub enum OcrError {
+    WrongHeight { height: usize },
+    UnknownGlyph { position: usize, glyph: String },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::WrongHeight { height } => write!(
+                f,
+                "the picture is {} pixels high, letters are {}",
+                height, HEIGHT
+            ),
+            OcrError::UnknownGlyph { position, glyph } => {
+                write!(f, "letter {} is not one I know:\n{}", position + 1, glyph)
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+fn lit(pixels: &[Vec<bool>], x: usize, y: usize) -> bool {
+    pixels[y].get(x).cloned().unwrap_or(false)
+}
+
+/// The glyph in columns `columns` as text, one `#` or `.` per pixel.
+fn glyph(pixels: &[Vec<bool>], columns: &[usize]) -> Vec<String> {
+    (0..HEIGHT)
+        .map(|y| {
+            columns
+                .iter()
+                .map(|&x| if lit(pixels, x, y) { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
+
+/// Reads the letters in a picture, `true` for lit pixels, one row per line.
+/// Letters are found as runs of columns with something lit in them, so it
+/// doesn't matter how much blank space there is around or between them.
+pub fn recognize(pixels: &[Vec<bool>]) -> Result<String, OcrError> {
+    if pixels.len() != HEIGHT {
+        return Err(OcrError::WrongHeight {
+            height: pixels.len(),
+        });
+    }
+
+    let width = pixels.iter().map(Vec::len).max().unwrap_or(0);
+    let mut letters = Vec::new();
+    let mut columns = Vec::new();
+
+    for x in 0..=width {
+        if x < width && (0..HEIGHT).any(|y| lit(pixels, x, y)) {
+            columns.push(x);
+        } else if !columns.is_empty() {
+            letters.push(glyph(pixels, &columns));
+            columns.clear();
+        }
+    }
+
+    letters
+        .into_iter()
+        .enumerate()
+        .map(|(position, rows)| {
+            GLYPHS
+                .iter()
+                .find(|(_, glyph)| glyph.iter().zip(&rows).all(|(a, b)| *a == b.as_str()))
+                .map(|&(letter, _)| letter)
+                .ok_or_else(|| OcrError::UnknownGlyph {
+                    position,
+                    glyph: rows.join("\n"),
+                })
+        })
+        .collect()
+}
+
+/// The picture as text, `█` for dark pixels and a space for lit ones, the way
+/// the days print it.
+pub fn render(pixels: &[Vec<bool>]) -> String {
+    let mut image = String::new();
+
+    for row in pixels {
+        for &pixel in row {
+            image.push(if pixel { ' ' } else { '█' });
+        }
+
+        image.push('\n');
+    }
+
+    image
+}
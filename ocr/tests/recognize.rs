@@ -0,0 +1,58 @@
+use ocr::{recognize, render, OcrError};
+
+fn pixels(rows: &[&str]) -> Vec<Vec<bool>> {
+    rows.iter()
+        .map(|row| row.chars().map(|c| c == '#').collect())
+        .collect()
+}
+
+#[test]
+fn reads_letters_wherever_they_start() {
+    let picture = pixels(&[
+        "..##..#..#.####.",
+        ".#..#.#..#.#....",
+        ".#..#.####.###..",
+        ".####.#..#.#....",
+        ".#..#.#..#.#....",
+        ".#..#.#..#.####.",
+    ]);
+
+    assert_eq!(recognize(&picture), Ok(String::from("AHE")));
+}
+
+#[test]
+fn renders_like_the_days_print() {
+    let picture = pixels(&["#.", ".#"]);
+
+    assert_eq!(render(&picture), " █\n█ \n");
+}
+
+#[test]
+fn rejects_unknown_glyphs() {
+    let picture = pixels(&[
+        "####.#..#",
+        "#....#..#",
+        "###..#..#",
+        "#....####",
+        "#.......#",
+        "#.......#",
+    ]);
+
+    assert_eq!(
+        recognize(&picture),
+        Err(OcrError::UnknownGlyph {
+            position: 1,
+            glyph: String::from("#..#\n#..#\n#..#\n####\n...#\n...#"),
+        })
+    );
+}
+
+#[test]
+fn rejects_pictures_of_the_wrong_height() {
+    let picture = pixels(&["####", "#..#"]);
+
+    assert_eq!(
+        recognize(&picture),
+        Err(OcrError::WrongHeight { height: 2 })
+    );
+}
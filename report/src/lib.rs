@@ -0,0 +1,143 @@
+use serde::Serialize;
+use std::env;
+use std::fmt::Display;
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "\
+arguments: [input] [--format text|json]
+    [input]          the puzzle input, - for standard input; input.txt by default
+    --format <name>  text, the default, prints just the answers; json prints an
+                     object with the answer and timings for every part";
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Format {
+    /// Just the answers, one per line.
+    Text,
+    /// A JSON object for every part, one per line.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("there is no {} format", s)),
+        }
+    }
+}
+
+/// What the JSON format prints for a part.
+#[derive(Serialize)]
+struct PartReport<'a> {
+    day: usize,
+    part: usize,
+    answer: &'a str,
+    elapsed_ms: f64,
+    diagnostics: &'a [String],
+}
+
+/// Times a day's parsing and parts and prints their answers in `format`.
+pub struct Report {
+    day: usize,
+    format: Format,
+    parse_time: Option<Duration>,
+}
+
+impl Report {
+    pub fn init(day: usize, format: Format) -> Report {
+        Report {
+            day,
+            format,
+            parse_time: None,
+        }
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    pub fn parse<T>(&mut self, parse: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let parsed = parse();
+        self.parse_time = Some(start.elapsed());
+
+        parsed
+    }
+
+    pub fn part<A: Display>(&self, part: usize, solve: impl FnOnce() -> A) {
+        self.part_with_diagnostics(part, |_| solve());
+    }
+
+    /// Like `part`, for solutions with more to say than the answer, such as
+    /// day 12's periods or the values day 23's NAT sends. Text reports print
+    /// the diagnostics to standard error.
+    pub fn part_with_diagnostics<A: Display>(
+        &self,
+        part: usize,
+        solve: impl FnOnce(&mut Vec<String>) -> A,
+    ) {
+        let mut diagnostics = Vec::new();
+
+        let start = Instant::now();
+        let answer = solve(&mut diagnostics).to_string();
+        let elapsed = start.elapsed();
+
+        match self.format {
+            Format::Text => {
+                for diagnostic in &diagnostics {
+                    eprintln!("{}", diagnostic);
+                }
+
+                if answer.ends_with('\n') {
+                    print!("{}", answer);
+                } else {
+                    println!("{}", answer);
+                }
+            }
+            Format::Json => {
+                if let Some(parse_time) = self.parse_time {
+                    diagnostics.push(format!("input parsed in {:?}", parse_time));
+                }
+
+                let report = PartReport {
+                    day: self.day,
+                    part,
+                    answer: &answer,
+                    elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+                    diagnostics: &diagnostics,
+                };
+
+                println!("{}", serde_json::to_string(&report).unwrap());
+            }
+        }
+    }
+}
+
+/// The format named by `--format` in `args`, text if there is none.
+pub fn parse_format(mut args: impl Iterator<Item = String>) -> Result<Format, String> {
+    let mut format = Format::Text;
+
+    while let Some(arg) = args.next() {
+        if arg == "--format" {
+            format = args.next().ok_or("--format needs a name")?.parse()?;
+        }
+    }
+
+    Ok(format)
+}
+
+/// A report for `day` in the format asked for on the command line, or the
+/// usage and an exit if the format is wrong.
+pub fn report(day: usize) -> Report {
+    let format = parse_format(env::args().skip(1)).unwrap_or_else(|why| {
+        eprintln!("{}\n{}", why, USAGE);
+        process::exit(1);
+    });
+
+    Report::init(day, format)
+}